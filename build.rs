@@ -0,0 +1,92 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Generates one test per entry of the examples manifests in `src/examples/<year>/NN.json`.
+ */
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// The registered days of a year as `(day, module, solution)`, read from its `solutions!` list.
+fn registered_days(year_dir: &Path) -> Vec<(u8, String, String)> {
+    let registry = fs::read_to_string(year_dir.join("mod.rs")).unwrap_or_default();
+    registry
+        .lines()
+        .filter_map(|line| line.trim().trim_end_matches(',').split_once(" => "))
+        .filter_map(|(day, path)| {
+            let (module, solution) = path.split_once("::")?;
+            // Entries can end in `with generate` or `with reference`, see `solutions!`.
+            let solution = solution.split_whitespace().next()?;
+            Some((day.parse().ok()?, module.to_string(), solution.to_string()))
+        })
+        .collect()
+}
+
+/// Turns an example name into something that can be part of a test name.
+fn identifier(name: &str) -> String {
+    name.chars()
+        .map(|c| match c.to_ascii_lowercase() {
+            c @ ('a'..='z' | '0'..='9') => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/examples");
+    println!("cargo:rerun-if-changed=src/solutions");
+
+    let mut years: Vec<u16> = fs::read_dir("src/examples")
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+        .collect();
+    years.sort_unstable();
+
+    let mut tests = String::new();
+    for year in years {
+        writeln!(tests, "mod y{} {{", year).unwrap();
+        for (day, module, solution) in
+            registered_days(&Path::new("src/solutions").join(format!("y{}", year)))
+        {
+            let manifest = format!("src/examples/{}/{:02}.json", year, day);
+            let Ok(contents) = fs::read_to_string(&manifest) else {
+                continue;
+            };
+            let check = format!(
+                "crate::examples::check::<crate::solutions::y{}::{}::{}>",
+                year, module, solution
+            );
+
+            match serde_json::from_str::<Vec<serde_json::Value>>(&contents) {
+                Ok(examples) => {
+                    for (index, example) in examples.iter().enumerate() {
+                        let mut name = format!("day{:02}_{}", day, index + 1);
+                        if let Some(label) = example.get("name").and_then(|n| n.as_str()) {
+                            name = format!("{}_{}", name, identifier(label));
+                        }
+                        writeln!(
+                            tests,
+                            "    #[test]\n    fn {}() {{\n        {}({}, {}, {});\n    }}",
+                            name, check, year, day, index
+                        )
+                        .unwrap();
+                    }
+                }
+                // Reported by a failing test rather than breaking the build.
+                Err(_) => writeln!(
+                    tests,
+                    "    #[test]\n    fn day{:02}_manifest() {{\n        crate::examples::load({}, {}).unwrap();\n    }}",
+                    day, year, day
+                )
+                .unwrap(),
+            }
+        }
+        writeln!(tests, "}}").unwrap();
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("example_tests.rs"), tests).unwrap();
+}
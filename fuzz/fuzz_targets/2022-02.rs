@@ -0,0 +1,6 @@
+#![no_main]
+
+use advent_of_code::solutions::y2022::day02::Day02;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| advent_of_code::props::fuzz::<Day02>(data));
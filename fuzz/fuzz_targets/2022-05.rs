@@ -0,0 +1,6 @@
+#![no_main]
+
+use advent_of_code::solutions::y2022::day05::Day05;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| advent_of_code::props::fuzz::<Day05>(data));
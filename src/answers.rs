@@ -0,0 +1,211 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::Answer;
+
+/// Accepted answers for the real puzzle inputs, committed alongside the solutions.
+pub const ANSWERS_PATH: &str = "src/answers.json";
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<Answer>,
+}
+
+impl DayAnswers {
+    pub fn part(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part_one.as_ref(),
+            2 => self.part_two.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn part_mut(&mut self, part: u8) -> &mut Option<Answer> {
+        match part {
+            1 => &mut self.part_one,
+            2 => &mut self.part_two,
+            _ => panic!("invalid part: {}", part),
+        }
+    }
+}
+
+/// Accepted answers by year and day.
+pub type AnswerStore = BTreeMap<u16, BTreeMap<u8, DayAnswers>>;
+
+fn load_json<T: DeserializeOwned + Default>(path: &Path) -> io::Result<T> {
+    match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents).map_err(io::Error::from),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(e),
+    }
+}
+
+fn save_json<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    let mut contents = serde_json::to_string_pretty(value)?;
+    contents.push('\n');
+    fs::write(path, contents)
+}
+
+/// Loads the answer store, a missing file counts as an empty store.
+pub fn load(path: &Path) -> io::Result<AnswerStore> {
+    load_json(path)
+}
+
+pub fn save(path: &Path, store: &AnswerStore) -> io::Result<()> {
+    save_json(path, store)
+}
+
+/// Answers the website rejected, so that they are never submitted twice.
+pub const GUESSES_PATH: &str = "src/guesses.json";
+
+/// Whether a rejected answer was too high or too low, if the website said so.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Guess {
+    pub answer: Answer,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+/// Wrong guesses by year, day and part.
+pub type GuessHistory = BTreeMap<u16, BTreeMap<u8, BTreeMap<u8, Vec<Guess>>>>;
+
+pub fn load_guesses(path: &Path) -> io::Result<GuessHistory> {
+    load_json(path)
+}
+
+pub fn save_guesses(path: &Path, history: &GuessHistory) -> io::Result<()> {
+    save_json(path, history)
+}
+
+/// Finds the earlier wrong guess that rules out `answer`: either the same answer, or a guess
+/// that was too high (too low) with `answer` not below (above) it.
+pub fn known_wrong<'a>(guesses: &'a [Guess], answer: &Answer) -> Option<&'a Guess> {
+    guesses.iter().find(|guess| {
+        if &guess.answer == answer {
+            return true;
+        }
+        match (&guess.answer, answer, guess.hint) {
+            (Answer::Number(guess), Answer::Number(answer), Some(Hint::TooHigh)) => answer >= guess,
+            (Answer::Number(guess), Answer::Number(answer), Some(Hint::TooLow)) => answer <= guess,
+            _ => false,
+        }
+    })
+}
+
+/// Result of comparing a computed answer with the accepted one.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        expected: Answer,
+        actual: Option<Answer>,
+    },
+    /// There is no accepted answer to compare against yet.
+    Unknown,
+}
+
+pub fn verify(expected: Option<&Answer>, actual: Option<&Answer>) -> Verdict {
+    match (expected, actual) {
+        (None, _) => Verdict::Unknown,
+        (Some(expected), Some(actual)) if expected == actual => Verdict::Correct,
+        (Some(expected), actual) => Verdict::Incorrect {
+            expected: expected.clone(),
+            actual: actual.cloned(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_store_roundtrip() {
+        let mut store = AnswerStore::new();
+        let year = store.entry(2022).or_default();
+        year.insert(
+            5,
+            DayAnswers {
+                part_one: Some("CMZ".into()),
+                part_two: None,
+            },
+        );
+        year.insert(
+            17,
+            DayAnswers {
+                part_one: Some(3068.into()),
+                part_two: Some(1_514_285_714_288_i64.into()),
+            },
+        );
+        let json = serde_json::to_string(&store).unwrap();
+        assert_eq!(
+            json,
+            r#"{"2022":{"5":{"part_one":"CMZ"},"17":{"part_one":3068,"part_two":1514285714288}}}"#
+        );
+        assert_eq!(serde_json::from_str::<AnswerStore>(&json).unwrap(), store);
+    }
+
+    #[test]
+    fn test_known_wrong() {
+        let guesses = vec![
+            Guess {
+                answer: 100.into(),
+                hint: Some(Hint::TooHigh),
+            },
+            Guess {
+                answer: 20.into(),
+                hint: Some(Hint::TooLow),
+            },
+            Guess {
+                answer: "ABC".into(),
+                hint: None,
+            },
+        ];
+        assert_eq!(known_wrong(&guesses, &50.into()), None);
+        assert_eq!(known_wrong(&guesses, &100.into()), Some(&guesses[0]));
+        assert_eq!(known_wrong(&guesses, &120.into()), Some(&guesses[0]));
+        assert_eq!(known_wrong(&guesses, &3.into()), Some(&guesses[1]));
+        assert_eq!(known_wrong(&guesses, &"ABC".into()), Some(&guesses[2]));
+        assert_eq!(known_wrong(&guesses, &"ABD".into()), None);
+    }
+
+    #[test]
+    fn test_verify() {
+        let expected: Answer = 24000.into();
+        assert_eq!(verify(None, Some(&expected)), Verdict::Unknown);
+        assert_eq!(verify(Some(&expected), Some(&expected)), Verdict::Correct);
+        assert_eq!(
+            verify(Some(&expected), Some(&45000.into())),
+            Verdict::Incorrect {
+                expected: expected.clone(),
+                actual: Some(45000.into()),
+            }
+        );
+        assert_eq!(
+            verify(Some(&expected), None),
+            Verdict::Incorrect {
+                expected,
+                actual: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,409 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::answers::Hint;
+use crate::{lazy_regex, Answer};
+
+/// The puzzle year this repository solves.
+pub const YEAR: u16 = 2022;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+/// Overrides the session cookie from `~/.adventofcode.session`.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Overrides the website URL, e.g. to point the commands at a local stub server.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// Overrides the directory downloaded inputs are cached in.
+pub const CACHE_DIR_ENV: &str = "AOC_CACHE_DIR";
+
+const USER_AGENT: &str = concat!(
+    "github.com/theshortcut/advent-of-code-2022 (",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    ")"
+);
+
+/// The website's verdict on a submitted answer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubmitOutcome {
+    Correct,
+    Incorrect(Option<Hint>),
+    /// Answers were submitted too quickly, the next one is accepted after the given time.
+    RateLimited(Duration),
+    /// The part has been solved already, or is not unlocked yet.
+    WrongLevel,
+    /// A response the outcome could not be determined from, with its message.
+    Unknown(String),
+}
+
+fn parse_wait_time(message: &str) -> Option<Duration> {
+    let regex = lazy_regex!(r"(?i)you have (?:(\d+)m )?(\d+)s left to wait");
+    let captures = regex.captures(message)?;
+    let minutes: u64 = captures.get(1).map_or(Ok(0), |m| m.as_str().parse()).ok()?;
+    let seconds: u64 = captures[2].parse().ok()?;
+    Some(Duration::from_secs(minutes * 60 + seconds))
+}
+
+/// Extracts the outcome from the HTML page returned for a submitted answer.
+pub fn parse_submit_response(html: &str) -> SubmitOutcome {
+    let message = lazy_regex!(r"(?s)<article>(.*?)</article>")
+        .captures(html)
+        .map_or(html, |captures| captures.get(1).unwrap().as_str());
+    let message = lazy_regex!(r"<[^>]*>")
+        .replace_all(message, "")
+        .trim()
+        .to_string();
+
+    if message.contains("That's the right answer") {
+        SubmitOutcome::Correct
+    } else if message.contains("That's not the right answer") {
+        let hint = if message.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if message.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        SubmitOutcome::Incorrect(hint)
+    } else if message.contains("You gave an answer too recently") {
+        SubmitOutcome::RateLimited(parse_wait_time(&message).unwrap_or_default())
+    } else if message.contains("You don't seem to be solving the right level") {
+        SubmitOutcome::WrongLevel
+    } else {
+        SubmitOutcome::Unknown(message)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum AocError {
+    #[error(
+        "no session cookie found, set {} or save it to \"~/{}\"",
+        SESSION_ENV,
+        SESSION_FILE
+    )]
+    MissingSession,
+    #[error("the session cookie was rejected, it might have expired")]
+    InvalidSession,
+    #[error("day {0} is not unlocked yet")]
+    NotFound(u8),
+    #[error("unexpected response ({status}): {body}")]
+    Http { status: u16, body: String },
+    #[error("request failed: {0}")]
+    Transport(String),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+/// Reads the session cookie from `AOC_SESSION`, falling back to `~/.adventofcode.session`.
+pub fn read_session() -> Result<String, AocError> {
+    let session = match env::var(SESSION_ENV) {
+        Ok(session) => session,
+        Err(_) => home_dir()
+            .map(|home| home.join(SESSION_FILE))
+            .and_then(|path| fs::read_to_string(path).ok())
+            .unwrap_or_default(),
+    };
+    match session.trim() {
+        "" => Err(AocError::MissingSession),
+        session => Ok(session.to_string()),
+    }
+}
+
+/// Directory downloaded inputs are cached in, `~/.cache/advent-of-code` unless configured.
+pub fn cache_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(CACHE_DIR_ENV) {
+        return PathBuf::from(dir);
+    }
+    env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| home_dir().map(|home| home.join(".cache")))
+        .unwrap_or_else(env::temp_dir)
+        .join("advent-of-code")
+}
+
+/// The input for `day` of `year` from `cache_dir`, if it has been downloaded before. Reading it
+/// needs no session, so cached inputs can be used offline.
+pub fn cached_input(cache_dir: &Path, year: u16, day: u8) -> Option<String> {
+    let cache_path = cached_input_path(cache_dir, year, day);
+    fs::read_to_string(cache_path)
+        .ok()
+        .filter(|input| !input.is_empty())
+}
+
+fn cached_input_path(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+    cache_dir
+        .join(year.to_string())
+        .join(format!("{:02}.txt", day))
+}
+
+/// Writes `contents` to a temporary file next to `path` and renames it into place, so readers
+/// and concurrent writers never see a partially written file.
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let tmp_path = path.with_file_name(format!(".{}.{}.tmp", file_name, process::id()));
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp_path);
+    })
+}
+
+/// A client for the Advent of Code website.
+pub struct Client {
+    base_url: String,
+    session: String,
+    year: u16,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, year: u16) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            year,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// A client for `year` configured from the environment, see [`read_session`].
+    pub fn from_env(year: u16) -> Result<Self, AocError> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(&base_url, &read_session()?, year))
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The website this client talks to, without a trailing `/`.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day)
+    }
+
+    fn send(
+        &self,
+        day: u8,
+        request: ureq::Request,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, AocError> {
+        let request = request.set("Cookie", &format!("session={}", self.session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(400, _)) => Err(AocError::InvalidSession),
+            Err(ureq::Error::Status(404, _)) => Err(AocError::NotFound(day)),
+            Err(ureq::Error::Status(status, response)) => Err(AocError::Http {
+                status,
+                body: response.into_string().unwrap_or_default(),
+            }),
+            Err(ureq::Error::Transport(e)) => Err(AocError::Transport(e.to_string())),
+        }
+    }
+
+    pub fn get(&self, day: u8, path: &str) -> Result<String, AocError> {
+        let url = format!("{}{}", self.day_url(day), path);
+        self.send(day, self.agent.get(&url), None)
+    }
+
+    pub fn post(&self, day: u8, path: &str, form: &[(&str, &str)]) -> Result<String, AocError> {
+        let url = format!("{}{}", self.day_url(day), path);
+        self.send(day, self.agent.post(&url), Some(form))
+    }
+
+    /// Downloads the puzzle input for `day`. Inputs never change, so they are cached in
+    /// `cache_dir` and only requested from the website once.
+    pub fn input(&self, day: u8, cache_dir: &Path) -> Result<String, AocError> {
+        if let Some(input) = cached_input(cache_dir, self.year, day) {
+            return Ok(input);
+        }
+        let input = self.get(day, "/input")?;
+        write_atomic(&cached_input_path(cache_dir, self.year, day), &input)?;
+        Ok(input)
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &Answer) -> Result<SubmitOutcome, AocError> {
+        let level = part.to_string();
+        let answer = answer.to_string();
+        let form = [("level", level.as_str()), ("answer", answer.as_str())];
+        let html = self.post(day, "/answer", &form)?;
+        Ok(parse_submit_response(&html))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    /// A request received by the stub server.
+    pub struct Request {
+        pub head: String,
+        pub body: String,
+    }
+
+    /// Serves each of `responses` (status, body) to one request, in order. Returns the base URL
+    /// of the server and a channel with the requests it received.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut head = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    head.push_str(&line);
+                }
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                let body = String::from_utf8(request_body).unwrap();
+                sender.send(Request { head, body }).unwrap();
+            }
+        });
+        (url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_is_downloaded_once() {
+        let (url, requests) = stub::serve(vec![(200, "1000\n2000\n")]);
+        let cache = env::temp_dir().join(format!("aoc-cache-test-{}", process::id()));
+        let client = Client::new(&url, "abc123", 2022);
+
+        assert_eq!(client.input(1, &cache).unwrap(), "1000\n2000\n");
+        let request = requests.recv().unwrap();
+        assert!(request.head.starts_with("GET /2022/day/1/input "));
+        assert!(request.head.contains("session=abc123"));
+        assert!(request.head.contains(USER_AGENT));
+        assert!(request.body.is_empty());
+
+        // The stub only answers once, a second request would fail.
+        assert_eq!(client.input(1, &cache).unwrap(), "1000\n2000\n");
+        assert_eq!(
+            cached_input(&cache, 2022, 1).as_deref(),
+            Some("1000\n2000\n")
+        );
+        assert_eq!(cached_input(&cache, 2022, 2), None);
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let html = "<main><article><p>That's the right answer! You are one gold star closer.</p></article></main>";
+        let (url, requests) = stub::serve(vec![(200, html)]);
+        let client = Client::new(&url, "abc123", 2022);
+
+        let outcome = client.submit(5, 2, &"MCD".into()).unwrap();
+        assert_eq!(outcome, SubmitOutcome::Correct);
+        let request = requests.recv().unwrap();
+        assert!(request.head.starts_with("POST /2022/day/5/answer "));
+        assert_eq!(request.body, "level=2&answer=MCD");
+    }
+
+    #[test]
+    fn test_parse_submit_response() {
+        let article = |message: &str| format!("<main><article><p>{}</p></article></main>", message);
+        assert_eq!(
+            parse_submit_response(&article(
+                "That's not the right answer; your answer is too high. If you're stuck, ..."
+            )),
+            SubmitOutcome::Incorrect(Some(Hint::TooHigh))
+        );
+        assert_eq!(
+            parse_submit_response(&article(
+                "That's not the right answer; your answer is too low."
+            )),
+            SubmitOutcome::Incorrect(Some(Hint::TooLow))
+        );
+        assert_eq!(
+            parse_submit_response(&article(
+                "That's not the right answer. If you're stuck, ..."
+            )),
+            SubmitOutcome::Incorrect(None)
+        );
+        assert_eq!(
+            parse_submit_response(&article("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2022/day/1\">[Return to Day 1]</a>")),
+            SubmitOutcome::RateLimited(Duration::from_secs(65))
+        );
+        assert_eq!(
+            parse_submit_response(&article(
+                "You gave an answer too recently. You have 38s left to wait."
+            )),
+            SubmitOutcome::RateLimited(Duration::from_secs(38))
+        );
+        assert_eq!(
+            parse_submit_response(&article(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            SubmitOutcome::WrongLevel
+        );
+        assert_eq!(
+            parse_submit_response("<html>maintenance</html>"),
+            SubmitOutcome::Unknown("maintenance".to_string())
+        );
+    }
+
+    #[test]
+    fn test_error_responses() {
+        let (url, _requests) = stub::serve(vec![(400, ""), (404, ""), (500, "oops")]);
+        let client = Client::new(&url, "abc123", 2022);
+        assert!(matches!(client.get(1, ""), Err(AocError::InvalidSession)));
+        assert!(matches!(client.get(25, ""), Err(AocError::NotFound(25))));
+        assert!(matches!(
+            client.get(1, ""),
+            Err(AocError::Http { status: 500, .. })
+        ));
+    }
+}
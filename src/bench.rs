@@ -0,0 +1,253 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::fs;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cli::Parts;
+use crate::helpers::parse::ParseError;
+use crate::{Answer, Solution, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const WARMUP_RUNS: usize = 3;
+const MIN_SAMPLES: usize = 10;
+const MAX_SAMPLES: usize = 10_000;
+const TIME_BUDGET: Duration = Duration::from_secs(1);
+
+/// A day is flagged as regressed if its median got slower than the baseline by this factor.
+const REGRESSION_THRESHOLD: f64 = 1.1;
+
+/// Where the baseline for `year` is stored by default, one file per year next to the accepted
+/// answers, so that `cargo clean` does not delete it.
+pub fn baseline_path(year: u16) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join(format!("bench_baseline_{}.json", year))
+}
+
+/// Summary statistics over repeated runs of the same step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub std_dev: Duration,
+    pub samples: usize,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "need at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let count = sorted.len();
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / count as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / count as f64;
+        let median = if count.is_multiple_of(2) {
+            (nanos[count / 2 - 1] + nanos[count / 2]) / 2.0
+        } else {
+            nanos[count / 2]
+        };
+
+        Stats {
+            mean: Duration::from_nanos(mean.round() as u64),
+            median: Duration::from_nanos(median.round() as u64),
+            min: sorted[0],
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+            samples: count,
+        }
+    }
+}
+
+pub struct PartBench {
+    pub part: u8,
+    pub answer: Option<Answer>,
+    /// `None` if the part is not solved, unsolved parts are not sampled.
+    pub stats: Option<Stats>,
+}
+
+pub struct DayBench {
+    pub parse: Stats,
+    pub parts: Vec<PartBench>,
+}
+
+impl DayBench {
+    /// Sum of the median timings of parsing and all solved parts.
+    pub fn total_median(&self) -> Duration {
+        self.parts
+            .iter()
+            .filter_map(|part| part.stats)
+            .fold(self.parse.median, |total, stats| total + stats.median)
+    }
+}
+
+fn sample<T>(mut func: impl FnMut() -> T) -> Stats {
+    for _ in 0..WARMUP_RUNS {
+        black_box(func());
+    }
+
+    let mut samples = Vec::new();
+    let started = Instant::now();
+    while samples.len() < MIN_SAMPLES
+        || (samples.len() < MAX_SAMPLES && started.elapsed() < TIME_BUDGET)
+    {
+        let timer = Instant::now();
+        let result = func();
+        samples.push(timer.elapsed());
+        black_box(result);
+    }
+
+    Stats::from_samples(&samples)
+}
+
+fn bench_part(part: u8, func: impl Fn() -> Option<Answer>) -> PartBench {
+    let answer = func();
+    let stats = answer.as_ref().map(|_| sample(&func));
+    PartBench {
+        part,
+        answer,
+        stats,
+    }
+}
+
+/// Runs parsing and the selected parts of `S` repeatedly and collects timing statistics for each.
+/// Inputs that do not parse are not benchmarked.
+pub fn run<S: Solution>(input: &str, parts: Parts) -> Result<DayBench, ParseError> {
+    let parsed = S::parse(input)?;
+    let parse = sample(|| S::parse(input));
+    let parts = [(1, S::part_one as fn(&S::Input) -> _), (2, S::part_two)]
+        .into_iter()
+        .filter(|(part, _)| parts.includes(*part))
+        .map(|(part, func)| bench_part(part, || func(&parsed)))
+        .collect();
+    Ok(DayBench { parse, parts })
+}
+
+/// Median timings of a previous benchmark run, in nanoseconds.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub parse: u64,
+    pub part_one: Option<u64>,
+    pub part_two: Option<u64>,
+}
+
+pub type Baseline = BTreeMap<u8, BaselineEntry>;
+
+impl BaselineEntry {
+    pub fn part(&self, part: u8) -> Option<u64> {
+        match part {
+            1 => self.part_one,
+            2 => self.part_two,
+            _ => None,
+        }
+    }
+
+    /// Updates the entry with the timings of `bench`. Parts that were not benchmarked keep their
+    /// previous timings.
+    pub fn update(&mut self, bench: &DayBench) {
+        self.parse = bench.parse.median.as_nanos() as u64;
+        for part in &bench.parts {
+            let median = part.stats.map(|s| s.median.as_nanos() as u64);
+            match part.part {
+                1 => self.part_one = median,
+                _ => self.part_two = median,
+            }
+        }
+    }
+}
+
+pub fn load_baseline(path: &Path) -> Option<Baseline> {
+    let contents = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+pub fn save_baseline(path: &Path, baseline: &Baseline) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let contents = serde_json::to_string_pretty(baseline)?;
+    fs::write(path, contents)
+}
+
+/// Relative change of `median` compared to `baseline`, if it is a regression.
+fn regression(median: Duration, baseline: Option<u64>) -> Option<f64> {
+    let baseline = baseline? as f64;
+    let ratio = median.as_nanos() as f64 / baseline;
+    (baseline > 0.0 && ratio > REGRESSION_THRESHOLD).then_some(ratio - 1.0)
+}
+
+fn format_stats(stats: &Stats, baseline: Option<u64>) -> String {
+    let mut line = format!(
+        "{}(mean: {:.2?} ± {:.2?}, median: {:.2?}, min: {:.2?}, {} runs){}",
+        ANSI_ITALIC, stats.mean, stats.std_dev, stats.median, stats.min, stats.samples, ANSI_RESET
+    );
+    if let Some(change) = regression(stats.median, baseline) {
+        line.push_str(&format!(
+            " {}⚠️ regression: +{:.0}% vs. baseline{}",
+            ANSI_BOLD,
+            change * 100.0,
+            ANSI_RESET
+        ));
+    }
+    line
+}
+
+pub fn print_day(bench: &DayBench, baseline: Option<&BaselineEntry>) {
+    print!("{}", format_day(bench, baseline));
+}
+
+/// The output of [`print_day`], for callers that print it later.
+pub fn format_day(bench: &DayBench, baseline: Option<&BaselineEntry>) -> String {
+    let mut output = format!(
+        "Parse {}\n",
+        format_stats(&bench.parse, baseline.map(|b| b.parse))
+    );
+    for result in &bench.parts {
+        let baseline = baseline.and_then(|b| b.part(result.part));
+        output.push_str(&format!(
+            "🎄 {}Part {}{} 🎄\n",
+            ANSI_BOLD, result.part, ANSI_RESET
+        ));
+        match (&result.answer, &result.stats) {
+            (Some(answer), Some(stats)) => {
+                output.push_str(&format!("{} {}\n", answer, format_stats(stats, baseline)));
+            }
+            _ => output.push_str("not solved.\n"),
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples: Vec<Duration> = [4, 1, 3, 2, 10]
+            .iter()
+            .map(|n| Duration::from_nanos(*n))
+            .collect();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.mean, Duration::from_nanos(4));
+        assert_eq!(stats.median, Duration::from_nanos(3));
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.std_dev, Duration::from_nanos(3));
+        assert_eq!(stats.samples, 5);
+    }
+
+    #[test]
+    fn test_regression() {
+        assert_eq!(regression(Duration::from_nanos(100), None), None);
+        assert_eq!(regression(Duration::from_nanos(105), Some(100)), None);
+        assert!(regression(Duration::from_nanos(150), Some(100)).is_some());
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day01::Day01;
+
+fn main() {
+    advent_of_code::solve!(Day01, 2022, 1);
+}
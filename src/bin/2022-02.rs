@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day02::Day02;
+
+fn main() {
+    advent_of_code::solve!(Day02, 2022, 2);
+}
@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day03::Day03;
+
+fn main() {
+    advent_of_code::solve!(Day03, 2022, 3);
+}
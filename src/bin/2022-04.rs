@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day04::Day04;
+
+fn main() {
+    advent_of_code::solve!(Day04, 2022, 4);
+}
@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day05::Day05;
+
+fn main() {
+    advent_of_code::solve!(Day05, 2022, 5);
+}
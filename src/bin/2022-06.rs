@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day06::Day06;
+
+fn main() {
+    advent_of_code::solve!(Day06, 2022, 6);
+}
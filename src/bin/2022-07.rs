@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day07::Day07;
+
+fn main() {
+    advent_of_code::solve!(Day07, 2022, 7);
+}
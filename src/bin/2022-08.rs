@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day08::Day08;
+
+fn main() {
+    advent_of_code::solve!(Day08, 2022, 8);
+}
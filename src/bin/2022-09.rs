@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day09::Day09;
+
+fn main() {
+    advent_of_code::solve!(Day09, 2022, 9);
+}
@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day10::Day10;
+
+fn main() {
+    advent_of_code::solve!(Day10, 2022, 10);
+}
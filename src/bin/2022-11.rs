@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day11::Day11;
+
+fn main() {
+    advent_of_code::solve!(Day11, 2022, 11);
+}
@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day12::Day12;
+
+fn main() {
+    advent_of_code::solve!(Day12, 2022, 12);
+}
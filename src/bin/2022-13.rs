@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day13::Day13;
+
+fn main() {
+    advent_of_code::solve!(Day13, 2022, 13);
+}
@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day14::Day14;
+
+fn main() {
+    advent_of_code::solve!(Day14, 2022, 14);
+}
@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day15::Day15;
+
+fn main() {
+    advent_of_code::solve!(Day15, 2022, 15);
+}
@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day16::Day16;
+
+fn main() {
+    advent_of_code::solve!(Day16, 2022, 16);
+}
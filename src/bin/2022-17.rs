@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y2022::day17::Day17;
+
+fn main() {
+    advent_of_code::solve!(Day17, 2022, 17);
+}
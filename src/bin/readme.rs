@@ -0,0 +1,130 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use advent_of_code::answers;
+use advent_of_code::aoc;
+use advent_of_code::cli::{self, Parts};
+use advent_of_code::readme::{self, DayTimings};
+use advent_of_code::solutions;
+
+struct Args {
+    year: u16,
+    stars_only: bool,
+}
+
+fn parse_args() -> Result<Args, cli::ArgsError> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(aoc::YEAR),
+        stars_only: args.contains("--stars-only"),
+    };
+    cli::finish(args)?;
+    Ok(parsed)
+}
+
+/// Benchmarks every registered day of `year` that has an input.
+fn benchmark(year: u16) -> Vec<DayTimings> {
+    let mut timings = Vec::new();
+    for solution in solutions::days(year) {
+        let day = solution.day;
+        let input = match advent_of_code::read_file("inputs", year, day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Day {:02}: skipped, {}.", day, e.describe_input(year, day));
+                continue;
+            }
+        };
+        eprintln!("Day {:02}: benchmarking...", day);
+        let result = match (solution.bench)(&input, Parts::Both) {
+            Ok(result) => result,
+            Err(e) => {
+                eprintln!("Day {:02}: skipped, invalid input, {}.", day, e);
+                continue;
+            }
+        };
+        let median = |part| {
+            let part = result.parts.iter().find(|p| p.part == part)?;
+            part.stats.map(|stats| stats.median)
+        };
+        timings.push(DayTimings {
+            day,
+            part_one: median(1),
+            part_two: median(2),
+            total: result.total_median(),
+        });
+    }
+    timings
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    let year = args.year;
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    let store = match answers::load(&root.join(answers::ANSWERS_PATH)) {
+        Ok(store) => store,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", answers::ANSWERS_PATH, e);
+            process::exit(1);
+        }
+    };
+    let answers = store.get(&year).cloned().unwrap_or_default();
+    let last_day = solutions::days(year)
+        .iter()
+        .map(|solution| solution.day)
+        .chain(answers.keys().copied())
+        .max()
+        .unwrap_or(0);
+
+    let readme_path = root.join(readme::README_PATH);
+    let mut contents = match fs::read_to_string(&readme_path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", readme::README_PATH, e);
+            process::exit(1);
+        }
+    };
+
+    let stars = readme::section(&contents, readme::STARS_MARKER)
+        .map(|current| readme::stars_table(year, &answers, last_day, current));
+    let stars = match stars {
+        Ok(stars) => stars,
+        Err(e) => {
+            eprintln!("Failed to update \"{}\": {}", readme::README_PATH, e);
+            process::exit(1);
+        }
+    };
+    let mut sections = vec![(readme::STARS_MARKER, stars)];
+    if !args.stars_only {
+        let table = readme::benchmarks_table(year, &benchmark(year));
+        sections.push((readme::BENCHMARKS_MARKER, table));
+    }
+    for (marker, table) in sections {
+        contents = match readme::replace_section(&contents, marker, &table) {
+            Ok(contents) => contents,
+            Err(e) => {
+                eprintln!("Failed to update \"{}\": {}", readme::README_PATH, e);
+                process::exit(1);
+            }
+        };
+    }
+
+    if let Err(e) = fs::write(&readme_path, contents) {
+        eprintln!("Failed to write \"{}\": {}", readme::README_PATH, e);
+        process::exit(1);
+    }
+    println!("🎄 Updated the tables in \"{}\".", readme::README_PATH);
+}
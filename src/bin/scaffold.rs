@@ -2,113 +2,477 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    process,
-};
-
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use advent_of_code::aoc::{self, AocError, Client};
+use advent_of_code::cli::{self, Days};
+use advent_of_code::{puzzle, template};
+
+const TEMPLATES_DIR: &str = "templates";
+const REGISTRY_PATH: &str = "src/solutions/mod.rs";
+const FUZZ_MANIFEST_PATH: &str = "fuzz/Cargo.toml";
+
+struct Args {
+    year: u16,
+    days: Days,
+    template: String,
+    answer_type: String,
+    fetch: bool,
+    force: bool,
+    dry_run: bool,
+}
+
+fn parse_args() -> Result<Args, cli::ArgsError> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(aoc::YEAR),
+        template: args
+            .opt_value_from_str(["-t", "--template"])?
+            .unwrap_or_else(|| "default".to_string()),
+        answer_type: args
+            .opt_value_from_str("--answer-type")?
+            .unwrap_or_else(|| "u32".to_string()),
+        fetch: args.contains("--fetch"),
+        force: args.contains("--force"),
+        dry_run: args.contains("--dry-run"),
+        days: args.free_from_str()?,
+    };
+    cli::finish(args)?;
+    Ok(parsed)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+/// Downloads the puzzle page and returns it as markdown, along with its first code block.
+fn fetch_puzzle(year: u16, day: u8) -> Result<(String, Option<String>), AocError> {
+    let client = Client::from_env(year)?;
+    let html = client.get(day, "")?;
+    Ok((
+        puzzle::to_markdown(&html, client.base_url()),
+        puzzle::first_code_block(&html),
+    ))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// The binary, fuzz target and named solution templates a day is scaffolded from.
+struct Templates {
+    bin: String,
+    fuzz_target: String,
+    module: String,
+    answer_type: String,
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+impl Templates {
+    fn load(name: &str, answer_type: &str) -> Result<Templates, String> {
+        if name.is_empty() || name.contains(['/', '\\']) {
+            return Err(format!(
+                "Invalid template name \"{}\", pass the name of a file in {}/solutions without its extension",
+                name, TEMPLATES_DIR
+            ));
+        }
+        let dir = root().join(TEMPLATES_DIR);
+        let read = |path: PathBuf| {
+            fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read template \"{}\": {}", path.display(), e))
+        };
+        let module_path = dir.join("solutions").join(format!("{}.rs.tpl", name));
+        if !module_path.exists() {
+            return Err(format!(
+                "Unknown template \"{}\", available templates: {}",
+                name,
+                Templates::available().join(", ")
+            ));
+        }
+        Ok(Templates {
+            bin: read(dir.join("bin.rs.tpl"))?,
+            fuzz_target: read(dir.join("fuzz_target.rs.tpl"))?,
+            module: read(module_path)?,
+            answer_type: answer_type.to_string(),
+        })
+    }
+
+    /// Names of the templates in `templates/solutions`.
+    fn available() -> Vec<String> {
+        let dir = root().join(TEMPLATES_DIR).join("solutions");
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let file_name = entry.ok()?.file_name().to_string_lossy().to_string();
+                file_name.strip_suffix(".rs.tpl").map(str::to_string)
+            })
+            .collect();
+        names.sort();
+        names
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+    fn values(&self, year: u16, day: u8) -> HashMap<&'static str, String> {
+        HashMap::from([
+            ("day", day.to_string()),
+            ("day_padded", format!("{:02}", day)),
+            ("year", year.to_string()),
+            ("answer_type", self.answer_type.clone()),
+        ])
     }
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+/// A file scaffold writes. `path` is relative to the crate root.
+struct FileChange {
+    path: String,
+    description: String,
+    contents: String,
+    /// The current contents of the file if it exists, restored if scaffolding fails.
+    previous: Option<Vec<u8>>,
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+impl FileChange {
+    fn new(path: String, description: &str, contents: String) -> Self {
+        let previous = fs::read(root().join(&path)).ok();
+        FileChange {
+            path,
+            description: description.to_string(),
+            contents,
+            previous,
+        }
+    }
+
+    fn full_path(&self) -> PathBuf {
+        root().join(&self.path)
+    }
+
+    fn staging_path(&self) -> PathBuf {
+        let path = self.full_path();
+        let file_name = path.file_name().unwrap().to_string_lossy().to_string();
+        path.with_file_name(format!(".{}.{}.scaffold", file_name, process::id()))
+    }
+
+    fn rollback(&self) {
+        let _ = match &self.previous {
+            Some(previous) => fs::write(self.full_path(), previous),
+            None => fs::remove_file(self.full_path()),
+        };
+    }
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn remove_staged(paths: &[PathBuf]) {
+    for path in paths {
+        let _ = fs::remove_file(path);
+    }
+}
+
+/// Everything scaffold will write for one year, plus the existing files that are in the way.
+struct Plan {
+    year: u16,
+    /// A dry run only lists the puzzle descriptions it would fetch, so it never hits the network.
+    dry_run: bool,
+    changes: Vec<FileChange>,
+    conflicts: Vec<String>,
+    kept: Vec<String>,
+    registered: Vec<u8>,
+    fetches: Vec<String>,
+}
+
+/// Adds `entries` to the end of the `list!` macro invocation in `source`.
+fn insert_entries(source: &str, list: &str, entries: &[String]) -> io::Result<String> {
+    let end = source
+        .find(&format!("{}! {{", list))
+        .and_then(|start| source[start..].find("\n}").map(|end| start + end))
+        .ok_or_else(|| {
+            let message = format!("could not find `{}!` list", list);
+            io::Error::new(ErrorKind::InvalidData, message)
+        })?;
+
+    let mut updated = source[..end].to_string();
+    for entry in entries {
+        updated.push_str("\n    ");
+        updated.push_str(entry);
+    }
+    updated.push_str(&source[end..]);
+    Ok(updated)
+}
+
+impl Plan {
+    fn new(year: u16, dry_run: bool) -> Self {
+        Plan {
+            year,
+            dry_run,
+            changes: Vec::new(),
+            conflicts: Vec::new(),
+            kept: Vec::new(),
+            registered: Vec::new(),
+            fetches: Vec::new(),
+        }
+    }
+
+    /// Plans to create `path`. An existing file is a conflict, unless it is empty.
+    fn create(&mut self, path: String, description: &str, contents: String) {
+        let change = FileChange::new(path, description, contents);
+        if change
+            .previous
+            .as_ref()
+            .is_some_and(|previous| !previous.is_empty())
+        {
+            self.conflicts.push(change.path.clone());
+        }
+        self.changes.push(change);
+    }
+
+    fn add_day(&mut self, day: u8, templates: &Templates, fetch: bool) -> Result<(), String> {
+        let year = self.year;
+        let day_padded = format!("{:02}", day);
+        let values = templates.values(year, day);
+        let render = |template: &str, name: &str| {
+            template::render(template, &values)
+                .map_err(|e| format!("Failed to render {} template: {}", name, e))
+        };
+
+        self.create(
+            format!("src/solutions/y{}/day{}.rs", year, day_padded),
+            "module file",
+            render(&templates.module, "solution")?,
+        );
+        self.create(
+            format!("src/bin/{}-{}.rs", year, day_padded),
+            "binary file",
+            render(&templates.bin, "binary")?,
+        );
+        self.create(
+            format!("fuzz/fuzz_targets/{}-{}.rs", year, day_padded),
+            "fuzz target",
+            render(&templates.fuzz_target, "fuzz target")?,
+        );
+
+        // Downloaded inputs are never overwritten, not even with `--force`.
+        let input_path = format!("src/inputs/{}/{}.txt", year, day_padded);
+        if root().join(&input_path).exists() {
+            self.kept.push(input_path);
+        } else {
+            self.create(input_path, "empty input file", String::new());
+        }
+
+        let mut example = None;
+        let puzzle_path = format!("src/puzzles/{}/{}.md", year, day_padded);
+        if fetch && self.dry_run {
+            self.fetches.push(puzzle_path);
+        } else if fetch {
+            match fetch_puzzle(year, day) {
+                Ok((markdown, code_block)) => {
+                    self.create(puzzle_path, "puzzle description", markdown);
+                    example = code_block;
+                }
+                Err(e) => eprintln!("Failed to fetch puzzle description for day {}: {}", day, e),
+            }
+        }
+        let example_path = format!("src/examples/{}/{}.txt", year, day_padded);
+        match example {
+            Some(example) => self.create(example_path, "example file from the puzzle", example),
+            None => self.create(example_path, "empty example file", String::new()),
+        }
+        self.create(
+            format!("src/examples/{}/{}.json", year, day_padded),
+            "examples manifest",
+            format!(
+                "[\n  {{\"input\": \"{}.txt\", \"part_one\": null, \"part_two\": null}}\n]\n",
+                day_padded
+            ),
+        );
+
+        self.registered.push(day);
+        Ok(())
+    }
+
+    /// Plans to add all planned days to the year's `solutions!` list so the all-days runner
+    /// picks them up. The first day of a new year also creates the year's module.
+    fn register_days(&mut self) -> io::Result<()> {
+        let year_registry_path = format!("src/solutions/y{}/mod.rs", self.year);
+        let year_registry = match fs::read_to_string(root().join(&year_registry_path)) {
+            Ok(registry) => Some(registry),
+            Err(e) if e.kind() == ErrorKind::NotFound => None,
+            Err(e) => return Err(e),
+        };
+
+        let registered: HashSet<u8> = year_registry
+            .iter()
+            .flat_map(|registry| registry.lines())
+            .filter_map(|line| line.trim().split_once(" => "))
+            .filter_map(|(day, _)| day.parse().ok())
+            .collect();
+        self.registered.retain(|day| !registered.contains(day));
+        if self.registered.is_empty() {
+            return Ok(());
+        }
+
+        let entries: Vec<String> = self
+            .registered
+            .iter()
+            .map(|day| format!("{} => day{:02}::Day{:02},", day, day, day))
+            .collect();
+
+        let (source, previous) = match year_registry {
+            Some(registry) => (registry.clone(), Some(registry.into_bytes())),
+            None => {
+                let registry = fs::read_to_string(root().join(REGISTRY_PATH))?;
+                let entry = format!("{} => y{},", self.year, self.year);
+                self.changes.push(FileChange {
+                    path: REGISTRY_PATH.to_string(),
+                    description: "year registry".to_string(),
+                    contents: insert_entries(&registry, "years", &[entry])?,
+                    previous: Some(registry.into_bytes()),
+                });
+                let header = format!(
+                    "/*\n * Solutions for Advent of Code {}.\n * `cargo scaffold` adds new days to the `solutions!` list in this file.\n */\nsolutions! {{\n}}\n",
+                    self.year
+                );
+                (header, None)
+            }
+        };
+
+        self.changes.push(FileChange {
+            path: year_registry_path,
+            description: "solution registry".to_string(),
+            contents: insert_entries(&source, "solutions", &entries)?,
+            previous,
+        });
+
+        let fuzz_manifest = fs::read_to_string(root().join(FUZZ_MANIFEST_PATH))?;
+        let mut contents = fuzz_manifest.clone();
+        for day in &self.registered {
+            let name = format!("{}-{:02}", self.year, day);
+            contents.push_str(&format!(
+                "\n[[bin]]\nname = \"{}\"\npath = \"fuzz_targets/{}.rs\"\ntest = false\ndoc = false\nbench = false\n",
+                name, name
+            ));
+        }
+        self.changes.push(FileChange {
+            path: FUZZ_MANIFEST_PATH.to_string(),
+            description: "fuzz target list".to_string(),
+            contents,
+            previous: Some(fuzz_manifest.into_bytes()),
+        });
+        Ok(())
+    }
+
+    /// Writes all changes or none: every file is staged next to its target first, and only
+    /// moved into place once all of them were written. If a move fails, the files that were
+    /// already moved are restored.
+    fn apply(&self) -> io::Result<()> {
+        let mut staged = Vec::new();
+        for change in &self.changes {
+            let staging_path = change.staging_path();
+            let written = fs::create_dir_all(staging_path.parent().unwrap())
+                .and_then(|_| fs::write(&staging_path, &change.contents));
+            staged.push(staging_path);
+            if let Err(e) = written {
+                remove_staged(&staged);
+                return Err(e);
+            }
+        }
+
+        for (idx, change) in self.changes.iter().enumerate() {
+            if let Err(e) = fs::rename(&staged[idx], change.full_path()) {
+                self.changes[..idx].iter().for_each(FileChange::rollback);
+                remove_staged(&staged[idx..]);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+            eprintln!("Need to specify a day (as integer) or a range of days. example: `cargo scaffold 7`, `cargo scaffold 1..25`");
             process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", day);
-
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
-
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    let templates = match Templates::load(&args.template, &args.answer_type) {
+        Ok(templates) => templates,
         Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
+            eprintln!("{}", e);
             process::exit(1);
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
+    let mut plan = Plan::new(args.year, args.dry_run);
+    for day in args.days.iter() {
+        if let Err(e) = plan.add_day(day, &templates, args.fetch) {
+            eprintln!("{}", e);
             process::exit(1);
         }
     }
+    if let Err(e) = plan.register_days() {
+        eprintln!("Failed to read solution registry: {}", e);
+        process::exit(1);
+    }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    for path in &plan.kept {
+        println!("Keeping existing input file \"{}\"", path);
+    }
+    if !plan.conflicts.is_empty() {
+        for path in &plan.conflicts {
+            println!("\"{}\" already exists", path);
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
+        if !args.force {
+            eprintln!("Nothing was scaffolded. Use `--force` to overwrite these files.");
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    if args.dry_run {
+        for path in &plan.fetches {
+            println!(
+                "Would fetch puzzle description \"{}\" and take the example from it",
+                path
+            );
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
-            process::exit(1);
+        for change in &plan.changes {
+            let action = if change.previous.is_some() {
+                "overwrite"
+            } else {
+                "create"
+            };
+            println!(
+                "Would {} {} \"{}\"",
+                action, change.description, change.path
+            );
         }
+        return;
+    }
+
+    if let Err(e) = plan.apply() {
+        eprintln!("Failed to scaffold, no files were changed: {}", e);
+        process::exit(1);
+    }
+
+    for change in &plan.changes {
+        let action = if change.previous.is_some() {
+            "Updated"
+        } else {
+            "Created"
+        };
+        println!("{} {} \"{}\"", action, change.description, change.path);
     }
 
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    match args.days.iter().collect::<Vec<_>>().as_slice() {
+        [day] => println!(
+            "🎄 Type `cargo solve {}-{:02}` to run your solution.",
+            args.year, day
+        ),
+        _ if args.year == aoc::YEAR => println!("🎄 Type `cargo all` to run your solutions."),
+        _ => println!(
+            "🎄 Type `cargo all --year {}` to run your solutions.",
+            args.year
+        ),
+    }
 }
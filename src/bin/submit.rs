@@ -0,0 +1,162 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::Path;
+use std::process;
+
+use advent_of_code::answers::{self, Guess, Hint};
+use advent_of_code::aoc::{self, Client, SubmitOutcome};
+use advent_of_code::cli::{self, Parts};
+use advent_of_code::solutions;
+
+struct Args {
+    day: u8,
+    part: Parts,
+    year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, cli::ArgsError> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+        year: args.opt_value_from_str(["-y", "--year"])?,
+    };
+    cli::finish(args)?;
+    Ok(parsed)
+}
+
+fn exit_with_error(message: impl AsRef<str>) -> ! {
+    eprintln!("{}", message.as_ref());
+    process::exit(1);
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with_error(format!("Failed to process arguments: {}", e)),
+    };
+    let year = args.year.unwrap_or(aoc::YEAR);
+    let (day, part) = (args.day, if args.part == Parts::One { 1 } else { 2 });
+
+    let solution = match solutions::get(year, day) {
+        Some(solution) => solution,
+        None => exit_with_error(format!("{} Day {:02} is not solved.", year, day)),
+    };
+    let input = match advent_of_code::read_file("inputs", year, day) {
+        Ok(input) => input,
+        Err(e) => exit_with_error(format!("Day {:02}: {}", day, e.describe_input(year, day))),
+    };
+    let mut result = (solution.run)(&input, args.part)
+        .unwrap_or_else(|e| exit_with_error(format!("Day {:02}: invalid input, {}", day, e)));
+    let answer = match result.parts.pop() {
+        Some(result) => match result.answer {
+            Some(answer) => answer,
+            None => exit_with_error(format!("Day {:02} Part {} is not solved.", day, part)),
+        },
+        None => unreachable!("the selected part always runs"),
+    };
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let answers_path = &root.join(answers::ANSWERS_PATH);
+    let guesses_path = &root.join(answers::GUESSES_PATH);
+    let mut store = answers::load(answers_path).unwrap_or_else(|e| {
+        exit_with_error(format!(
+            "Failed to read \"{}\": {}",
+            answers_path.display(),
+            e
+        ))
+    });
+    let mut history = answers::load_guesses(guesses_path).unwrap_or_else(|e| {
+        exit_with_error(format!(
+            "Failed to read \"{}\": {}",
+            guesses_path.display(),
+            e
+        ))
+    });
+
+    if let Some(accepted) = store
+        .get(&year)
+        .and_then(|days| days.get(&day))
+        .and_then(|d| d.part(part))
+    {
+        if accepted == &answer {
+            println!("{} was already accepted, nothing to submit.", answer);
+            return;
+        }
+        exit_with_error(format!(
+            "{} was already accepted for this part, but the solution now returns {}. Run `cargo verify`.",
+            accepted, answer
+        ));
+    }
+
+    let guesses = history
+        .entry(year)
+        .or_default()
+        .entry(day)
+        .or_default()
+        .entry(part)
+        .or_default();
+    if let Some(guess) = answers::known_wrong(guesses, &answer) {
+        let reason = match guess.hint {
+            _ if guess.answer == answer => "was already rejected".to_string(),
+            Some(Hint::TooHigh) => format!("is not below {}, which was too high", guess.answer),
+            Some(Hint::TooLow) => format!("is not above {}, which was too low", guess.answer),
+            None => unreachable!("guesses without hint only rule out the same answer"),
+        };
+        exit_with_error(format!("Not submitting {}: it {}.", answer, reason));
+    }
+
+    let client = Client::from_env(year).unwrap_or_else(|e| exit_with_error(e.to_string()));
+
+    println!(
+        "Submitting {} for day {}, part {} ({})...",
+        answer,
+        day,
+        part,
+        client.year()
+    );
+
+    match client.submit(day, part, &answer) {
+        Ok(SubmitOutcome::Correct) => {
+            let days = store.entry(year).or_default();
+            *days.entry(day).or_default().part_mut(part) = Some(answer);
+            if let Err(e) = answers::save(answers_path, &store) {
+                exit_with_error(format!(
+                    "Failed to write \"{}\": {}",
+                    answers_path.display(),
+                    e
+                ));
+            }
+            println!("---");
+            println!(
+                "🎄 That's the right answer! Recorded it in \"{}\".",
+                answers_path.display()
+            );
+        }
+        Ok(SubmitOutcome::Incorrect(hint)) => {
+            let message = match hint {
+                Some(Hint::TooHigh) => "That's not the right answer, it is too high.",
+                Some(Hint::TooLow) => "That's not the right answer, it is too low.",
+                None => "That's not the right answer.",
+            };
+            guesses.push(Guess { answer, hint });
+            if let Err(e) = answers::save_guesses(guesses_path, &history) {
+                eprintln!("Failed to write \"{}\": {}", guesses_path.display(), e);
+            }
+            exit_with_error(message);
+        }
+        Ok(SubmitOutcome::RateLimited(wait)) => exit_with_error(format!(
+            "You gave an answer too recently, try again in {}s.",
+            wait.as_secs()
+        )),
+        Ok(SubmitOutcome::WrongLevel) => {
+            exit_with_error("This part is either solved already or not unlocked yet.")
+        }
+        Ok(SubmitOutcome::Unknown(message)) => {
+            exit_with_error(format!("Unexpected response: {}", message))
+        }
+        Err(e) => exit_with_error(format!("Failed to submit answer: {}", e)),
+    }
+}
@@ -0,0 +1,234 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::Path;
+use std::process;
+
+use advent_of_code::answers::{self, Verdict};
+use advent_of_code::aoc::Client;
+use advent_of_code::cli::{self, Parts};
+use advent_of_code::solutions::YEARS;
+use advent_of_code::{puzzle, Answer, PartResult, ANSI_BOLD, ANSI_RESET};
+
+struct Args {
+    record: bool,
+    cross_check: bool,
+    seed: u64,
+    cases: u64,
+    year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, cli::ArgsError> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        record: args.contains("--record"),
+        cross_check: args.contains("--cross-check"),
+        seed: args.opt_value_from_str("--seed")?.unwrap_or(0),
+        cases: args.opt_value_from_str("--cases")?.unwrap_or(500),
+        year: args.opt_value_from_str(["-y", "--year"])?,
+    };
+    cli::finish(args)?;
+    Ok(parsed)
+}
+
+/// The answers the website shows as accepted for `day`, in part order.
+fn fetch_accepted(year: u16, day: u8) -> Result<Vec<String>, String> {
+    let client = Client::from_env(year).map_err(|e| e.to_string())?;
+    let html = client.get(day, "").map_err(|e| e.to_string())?;
+    Ok(puzzle::accepted_answers(&html))
+}
+
+/// Compares every solution that is registered `with reference` to its reference on generated
+/// inputs, instead of checking the answers to the real inputs.
+fn cross_check(args: &Args) {
+    let mut failures = 0;
+    for year in YEARS
+        .iter()
+        .filter(|year| args.year.is_none_or(|y| y == year.year))
+    {
+        for solution in year.days {
+            let Some(cross_check) = solution.cross_check else {
+                continue;
+            };
+            let label = format!("{} Day {:02}", year.year, solution.day);
+            match cross_check(args.seed, args.cases) {
+                Ok(()) => println!("✅ {}: {} generated inputs match", label, args.cases),
+                Err(e) => {
+                    failures += 1;
+                    println!("❌ {}{}{}: {}", ANSI_BOLD, label, ANSI_RESET, e);
+                }
+            }
+        }
+    }
+
+    if failures > 0 {
+        println!(
+            "{}{} solution(s) disagree with their reference.{}",
+            ANSI_BOLD, failures, ANSI_RESET
+        );
+        process::exit(1);
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if args.cross_check {
+        cross_check(&args);
+        return;
+    }
+
+    let answers_path = &Path::new(env!("CARGO_MANIFEST_DIR")).join(answers::ANSWERS_PATH);
+    let mut store = match answers::load(answers_path) {
+        Ok(store) => store,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", answers_path.display(), e);
+            process::exit(1);
+        }
+    };
+
+    let mut failures = 0;
+    let mut recorded = 0;
+    let mut unknown = 0;
+
+    let solutions = YEARS
+        .iter()
+        .filter(|year| args.year.is_none_or(|y| y == year.year))
+        .flat_map(|year| year.days.iter().map(|solution| (year.year, solution)));
+
+    for (year, solution) in solutions {
+        let day = solution.day;
+        let input = match advent_of_code::read_file("inputs", year, day) {
+            Ok(input) => input,
+            Err(e) => {
+                let reason = e.describe_input(year, day);
+                println!("{} Day {:02}: skipped, {}.", year, day, reason);
+                continue;
+            }
+        };
+
+        let result = match (solution.run)(&input, Parts::Both) {
+            Ok(result) => result,
+            Err(e) => {
+                failures += 1;
+                println!(
+                    "❌ {}{} Day {:02}{}: invalid input, {}",
+                    ANSI_BOLD, year, day, ANSI_RESET, e
+                );
+                continue;
+            }
+        };
+        let expected = store.entry(year).or_default().entry(day).or_default();
+        // fetched once per day, and only to record answers
+        let mut website = None;
+
+        for PartResult {
+            part,
+            answer: actual,
+            ..
+        } in result.parts
+        {
+            let label = format!("{} Day {:02} Part {}", year, day, part);
+            match answers::verify(expected.part(part), actual.as_ref()) {
+                Verdict::Correct => println!("✅ {}: {}", label, actual.unwrap()),
+                Verdict::Incorrect { expected, actual } => {
+                    failures += 1;
+                    let actual = actual.map_or("not solved".to_string(), |a| a.to_string());
+                    println!(
+                        "❌ {}{}{}: expected {}, got {}",
+                        ANSI_BOLD, label, ANSI_RESET, expected, actual
+                    );
+                }
+                Verdict::Unknown => match actual {
+                    Some(actual) if args.record => {
+                        let website = website.get_or_insert_with(|| fetch_accepted(year, day));
+                        match website
+                            .as_ref()
+                            .map(|accepted| accepted.get(part as usize - 1))
+                        {
+                            Err(e) => {
+                                println!(
+                                    "❔ {}: {} (could not check it on the website: {})",
+                                    label, actual, e
+                                );
+                                unknown += 1;
+                            }
+                            Ok(None) => {
+                                println!(
+                                    "❔ {}: {} (not accepted on the website yet)",
+                                    label, actual
+                                );
+                                unknown += 1;
+                            }
+                            Ok(Some(accepted)) if *accepted == actual.to_string() => {
+                                println!("📝 {}: recorded {}", label, actual);
+                                *expected.part_mut(part) = Some(actual);
+                                recorded += 1;
+                            }
+                            // a drawing like day 10's can only be compared by looking at it
+                            Ok(Some(accepted)) if matches!(&actual, Answer::Text(text) if text.contains('\n')) =>
+                            {
+                                println!(
+                                    "❔ {}: the website accepted {}, add it to \"{}\" if the drawing shows it:\n{}",
+                                    label, accepted, answers::ANSWERS_PATH, actual
+                                );
+                                unknown += 1;
+                            }
+                            Ok(Some(accepted)) => {
+                                failures += 1;
+                                println!(
+                                    "❌ {}{}{}: the website accepted {}, got {}",
+                                    ANSI_BOLD, label, ANSI_RESET, accepted, actual
+                                );
+                            }
+                        }
+                    }
+                    Some(actual) => {
+                        println!("❔ {}: {} (no accepted answer)", label, actual);
+                        unknown += 1;
+                    }
+                    None => println!("➖ {}: not solved.", label),
+                },
+            }
+        }
+    }
+
+    for days in store.values_mut() {
+        days.retain(|_, day| day.part_one.is_some() || day.part_two.is_some());
+    }
+    store.retain(|_, days| !days.is_empty());
+
+    if recorded > 0 {
+        if let Err(e) = answers::save(answers_path, &store) {
+            eprintln!("Failed to write \"{}\": {}", answers_path.display(), e);
+            process::exit(1);
+        }
+        println!(
+            "Recorded {} answer(s) in \"{}\".",
+            recorded,
+            answers_path.display()
+        );
+    }
+
+    if unknown > 0 {
+        println!(
+            "{} answer(s) have no accepted value yet, run `cargo verify --record` to record the ones the website accepted.",
+            unknown
+        );
+    }
+
+    if failures > 0 {
+        println!(
+            "{}{} answer(s) did not match.{}",
+            ANSI_BOLD, failures, ANSI_RESET
+        );
+        process::exit(1);
+    }
+}
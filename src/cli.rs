@@ -0,0 +1,155 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// A selection of days, parsed from a list of days and inclusive ranges like `5,12-16` or `1..25`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Days(Vec<u8>);
+
+impl Days {
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl Default for Days {
+    fn default() -> Self {
+        Days((1..=25).collect())
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.trim().parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "invalid day \"{}\", expected a number from 1 to 25",
+            s
+        )),
+    }
+}
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut days = Vec::new();
+        for item in s.split(',') {
+            let range = item
+                .split_once("..=")
+                .or_else(|| item.split_once(".."))
+                .or_else(|| item.split_once('-'));
+            match range {
+                Some((start, end)) => {
+                    let (start, end) = (parse_day(start)?, parse_day(end)?);
+                    if start > end {
+                        return Err(format!("invalid range \"{}\"", item));
+                    }
+                    days.extend(start..=end);
+                }
+                None => days.push(parse_day(item)?),
+            }
+        }
+        days.sort_unstable();
+        days.dedup();
+        Ok(Days(days))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ArgsError {
+    #[error(transparent)]
+    Invalid(#[from] pico_args::Error),
+    #[error("unexpected argument(s): {}", .0.join(" "))]
+    Unexpected(Vec<String>),
+}
+
+/// Fails on arguments that no option consumed, e.g. a misspelled flag, instead of ignoring them.
+pub fn finish(args: pico_args::Arguments) -> Result<(), ArgsError> {
+    let unused: Vec<String> = args
+        .finish()
+        .into_iter()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
+    if unused.is_empty() {
+        Ok(())
+    } else {
+        Err(ArgsError::Unexpected(unused))
+    }
+}
+
+/// Which parts of a solution to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Parts {
+    #[default]
+    Both,
+    One,
+    Two,
+}
+
+impl Parts {
+    pub fn includes(self, part: u8) -> bool {
+        match self {
+            Parts::Both => true,
+            Parts::One => part == 1,
+            Parts::Two => part == 2,
+        }
+    }
+}
+
+impl FromStr for Parts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Parts::One),
+            "2" => Ok(Parts::Two),
+            _ => Err(format!("invalid part \"{}\", expected 1 or 2", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        let days: Days = "5,12-16,13".parse().unwrap();
+        assert_eq!(days.iter().collect::<Vec<_>>(), vec![5, 12, 13, 14, 15, 16]);
+        assert_eq!(Days::default().iter().count(), 25);
+        assert_eq!("1..25".parse::<Days>().unwrap(), Days::default());
+        assert_eq!("1..=25".parse::<Days>().unwrap(), Days::default());
+        assert!("0".parse::<Days>().is_err());
+        assert!("26".parse::<Days>().is_err());
+        assert!("16-12".parse::<Days>().is_err());
+        assert!("1,,2".parse::<Days>().is_err());
+    }
+
+    #[test]
+    fn test_parse_parts() {
+        assert_eq!("2".parse(), Ok(Parts::Two));
+        assert!("3".parse::<Parts>().is_err());
+        assert!(Parts::Both.includes(1) && Parts::Both.includes(2));
+        assert!(!Parts::One.includes(2));
+    }
+
+    #[test]
+    fn test_finish() {
+        let arguments = |args: &[&str]| {
+            let mut args = pico_args::Arguments::from_vec(args.iter().map(Into::into).collect());
+            let days: Option<Days> = args.opt_value_from_str("--days").unwrap();
+            finish(args).map(|_| days.is_some())
+        };
+        assert_eq!(arguments(&["--days", "5"]).ok(), Some(true));
+        assert_eq!(
+            arguments(&["--days", "5", "--input", "x.txt"])
+                .unwrap_err()
+                .to_string(),
+            "unexpected argument(s): --input x.txt"
+        );
+    }
+}
@@ -0,0 +1,149 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::de::{self, Deserializer};
+use serde::Deserialize;
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::params::Params;
+use crate::{Answer, ReadError, Solution};
+
+/// One example from a day's manifest: an input file next to the manifest, the parameters to run
+/// it with and the expected answers.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    #[serde(default)]
+    pub name: Option<String>,
+    pub input: String,
+    #[serde(default, deserialize_with = "params")]
+    pub params: BTreeMap<String, String>,
+    /// `None` if the part is not checked, `Some(None)` if the part is expected to be unsolved.
+    #[serde(default, deserialize_with = "expected")]
+    pub part_one: Option<Option<Answer>>,
+    #[serde(default, deserialize_with = "expected")]
+    pub part_two: Option<Option<Answer>>,
+}
+
+impl Example {
+    pub fn expected(&self, part: u8) -> Option<&Option<Answer>> {
+        match part {
+            1 => self.part_one.as_ref(),
+            _ => self.part_two.as_ref(),
+        }
+    }
+}
+
+/// Tells a missing expected answer (not checked) apart from `null` (expected to be unsolved).
+fn expected<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Option<Answer>>, D::Error> {
+    Option::<Answer>::deserialize(deserializer).map(Some)
+}
+
+/// Parameters are written as JSON strings or numbers and kept as text, like on the command line.
+fn params<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<BTreeMap<String, String>, D::Error> {
+    BTreeMap::<String, Value>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(name, value)| match value {
+            Value::String(value) => Ok((name, value)),
+            Value::Number(value) => Ok((name, value.to_string())),
+            other => Err(de::Error::custom(format!(
+                "parameter `{}` must be a string or a number, got {}",
+                name, other
+            ))),
+        })
+        .collect()
+}
+
+#[derive(Debug, Error)]
+pub enum ManifestError {
+    #[error("could not read \"{}\": {}", .0.display(), .1)]
+    Io(PathBuf, #[source] io::Error),
+    #[error("invalid manifest \"{}\": {}", .0.display(), .1)]
+    Json(PathBuf, #[source] serde_json::Error),
+}
+
+/// Path of the examples manifest for `day` of `year`, next to its example inputs.
+pub fn manifest_path(year: u16, day: u8) -> PathBuf {
+    crate::data_path("examples", year, day).with_extension("json")
+}
+
+/// Reads the examples manifest for `day` of `year`.
+pub fn load(year: u16, day: u8) -> Result<Vec<Example>, ManifestError> {
+    let path = manifest_path(year, day);
+    let contents = fs::read_to_string(&path).map_err(|e| ManifestError::Io(path.clone(), e))?;
+    serde_json::from_str(&contents).map_err(|e| ManifestError::Json(path, e))
+}
+
+/// Runs example `index` of the manifest for `day` of `year` and panics if an answer does not
+/// match. The tests generated from the manifests call this once per example. An empty example
+/// file, like the one `scaffold` creates, has not been filled in yet and is skipped.
+pub fn check<S: Solution>(year: u16, day: u8, index: usize) {
+    let examples = load(year, day).unwrap_or_else(|e| panic!("{}", e));
+    let example = &examples[index];
+    let label = format!(
+        "{} Day {:02} example {} ({})",
+        year,
+        day,
+        index + 1,
+        example.name.as_deref().unwrap_or(&example.input)
+    );
+    let params = Params::with_overrides(S::PARAMS, &example.params)
+        .and_then(|params| S::check_params(&params).map(|_| params))
+        .unwrap_or_else(|e| panic!("{}: {}", label, e));
+
+    let path = manifest_path(year, day).with_file_name(&example.input);
+    let input = match crate::read_path(&path) {
+        Ok(input) => input,
+        Err(ReadError::Empty(_)) => {
+            eprintln!("{}: skipped, the example input is empty", label);
+            return;
+        }
+        Err(e) => panic!("{}: {}", label, e),
+    };
+    let input = S::parse_with(&input, &params).unwrap_or_else(|e| panic!("{}: {}", label, e));
+    if let Some(expected) = example.expected(1) {
+        assert_eq!(&S::part_one(&input), expected, "{} part 1", label);
+    }
+    if let Some(expected) = example.expected(2) {
+        assert_eq!(&S::part_two(&input), expected, "{} part 2", label);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest() {
+        let examples: Vec<Example> = serde_json::from_str(
+            r#"[
+                {"input": "06.txt", "part_one": 7, "part_two": null},
+                {"name": "larger", "input": "06-2.txt", "params": {"row": 10, "start": "AA"}}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(examples[0].expected(1), Some(&Some(Answer::Number(7))));
+        assert_eq!(examples[0].expected(2), Some(&None));
+        assert_eq!(examples[1].expected(1), None);
+        assert_eq!(examples[1].params["row"], "10");
+        assert_eq!(examples[1].params["start"], "AA");
+
+        let invalid =
+            serde_json::from_str::<Vec<Example>>(r#"[{"input": "a", "params": {"row": [1]}}]"#);
+        assert!(invalid.is_err());
+    }
+
+    // One test per entry of every examples manifest, see `build.rs`.
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+}
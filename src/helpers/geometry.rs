@@ -0,0 +1,402 @@
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point or vector on a 2D plane. `y` grows downwards, like the rows of a puzzle input, so
+/// [`Dir4::Up`] is `(0, -1)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A point or vector in 3D space.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(self, other: Point2) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The vector with every component clamped to -1, 0 or 1, e.g. the step a rope knot takes
+    /// towards the knot in front of it.
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn neighbors4(self) -> impl Iterator<Item = Point2> {
+        Dir4::ALL.into_iter().map(move |dir| self + dir.delta())
+    }
+
+    pub fn neighbors8(self) -> impl Iterator<Item = Point2> {
+        Dir8::ALL.into_iter().map(move |dir| self + dir.delta())
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Point3) -> i64 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    /// The six points that share a face with this one.
+    pub fn neighbors6(self) -> impl Iterator<Item = Point3> {
+        [
+            Point3::new(1, 0, 0),
+            Point3::new(-1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 0, 1),
+            Point3::new(0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |delta| self + delta)
+    }
+}
+
+/// Implements the component-wise arithmetic operators for a point type.
+macro_rules! point_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, factor: i64) -> $point {
+                $point { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point2::new(x, y)
+    }
+}
+
+impl From<(usize, usize)> for Point2 {
+    /// Converts a [`Grid`](super::Grid) position.
+    fn from((x, y): (usize, usize)) -> Self {
+        Point2::new(x as i64, y as i64)
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// The four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// All directions, clockwise starting with up.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn delta(self) -> Point2 {
+        match self {
+            Dir4::Up => Point2::new(0, -1),
+            Dir4::Right => Point2::new(1, 0),
+            Dir4::Down => Point2::new(0, 1),
+            Dir4::Left => Point2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+}
+
+impl TryFrom<char> for Dir4 {
+    type Error = char;
+
+    /// Reads `U`/`R`/`D`/`L`, `N`/`E`/`S`/`W` and `^`/`>`/`v`/`<`.
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            'U' | 'N' | '^' => Ok(Dir4::Up),
+            'R' | 'E' | '>' => Ok(Dir4::Right),
+            'D' | 'S' | 'v' => Ok(Dir4::Down),
+            'L' | 'W' | '<' => Ok(Dir4::Left),
+            c => Err(c),
+        }
+    }
+}
+
+/// The four orthogonal and four diagonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// All directions, clockwise starting with north.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    pub fn delta(self) -> Point2 {
+        match self {
+            Dir8::N => Point2::new(0, -1),
+            Dir8::NE => Point2::new(1, -1),
+            Dir8::E => Point2::new(1, 0),
+            Dir8::SE => Point2::new(1, 1),
+            Dir8::S => Point2::new(0, 1),
+            Dir8::SW => Point2::new(-1, 1),
+            Dir8::W => Point2::new(-1, 0),
+            Dir8::NW => Point2::new(-1, -1),
+        }
+    }
+
+    /// Turns clockwise in steps of 45°.
+    pub fn rotate_right(self, steps: usize) -> Dir8 {
+        Dir8::ALL[(self as usize + steps) % 8]
+    }
+
+    /// Turns counterclockwise in steps of 45°.
+    pub fn rotate_left(self, steps: usize) -> Dir8 {
+        Dir8::ALL[(self as usize + 8 - steps % 8) % 8]
+    }
+
+    pub fn opposite(self) -> Dir8 {
+        self.rotate_right(4)
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+/// An inclusive range of integers, e.g. the part of a row a sensor covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    /// The interval from `start` to `end`, both included. Panics if `end` is before `start`.
+    pub fn new(start: i64, end: i64) -> Self {
+        assert!(start <= end, "interval {}..={} is empty", start, end);
+        Interval { start, end }
+    }
+
+    pub fn size(self) -> u64 {
+        self.start.abs_diff(self.end) + 1
+    }
+
+    pub fn contains(self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn overlaps(self, other: Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(self, other: Interval) -> Option<Interval> {
+        self.overlaps(other)
+            .then(|| Interval::new(self.start.max(other.start), self.end.min(other.end)))
+    }
+
+    /// The interval covering both, if they overlap or are directly next to each other.
+    pub fn union(self, other: Interval) -> Option<Interval> {
+        let (first, second) = if self.start <= other.start {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        (second.start <= first.end.saturating_add(1))
+            .then(|| Interval::new(first.start, first.end.max(second.end)))
+    }
+
+    /// Sorts `intervals` and joins those that overlap or touch, so the result covers the same
+    /// values with as few disjoint intervals as possible.
+    pub fn merge(mut intervals: Vec<Interval>) -> Vec<Interval> {
+        intervals.sort_unstable();
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) => match last.union(interval) {
+                    Some(union) => *last = union,
+                    None => merged.push(interval),
+                },
+                None => merged.push(interval),
+            }
+        }
+        merged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_points() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(-3, 4);
+        assert_eq!(a + b, Point2::new(-2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(-a * 2, Point2::new(-2, 4));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!((b - a).signum(), Point2::new(-1, 1));
+        assert_eq!(Point2::ORIGIN.neighbors8().count(), 8);
+
+        let mut c = Point3::new(1, 2, 3);
+        c += Point3::new(1, 1, 1);
+        assert_eq!(c, Point3::new(2, 3, 4));
+        assert_eq!(c.manhattan(Point3::ORIGIN), 9);
+        assert_eq!(c.chebyshev(Point3::ORIGIN), 4);
+        assert!(c.neighbors6().all(|n| n.manhattan(c) == 1));
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.opposite(), Dir4::Right);
+        assert_eq!(Dir4::try_from('<'), Ok(Dir4::Left));
+        assert_eq!(Dir4::try_from('x'), Err('x'));
+        assert_eq!(Dir8::N.rotate_right(3), Dir8::SE);
+        assert_eq!(Dir8::N.rotate_left(1), Dir8::NW);
+        assert_eq!(Dir8::SW.opposite(), Dir8::NE);
+        for dir in Dir4::ALL {
+            assert_eq!(Dir8::from(dir).delta(), dir.delta());
+        }
+    }
+
+    #[test]
+    fn test_intervals() {
+        let a = Interval::new(-2, 2);
+        assert_eq!(a.size(), 5);
+        assert!(a.contains(2) && !a.contains(3));
+        assert_eq!(
+            a.intersection(Interval::new(1, 9)),
+            Some(Interval::new(1, 2))
+        );
+        assert_eq!(a.intersection(Interval::new(3, 9)), None);
+        assert_eq!(a.union(Interval::new(3, 9)), Some(Interval::new(-2, 9)));
+        assert_eq!(a.union(Interval::new(4, 9)), None);
+        assert_eq!(
+            Interval::merge(vec![
+                Interval::new(12, 14),
+                Interval::new(-2, 2),
+                Interval::new(3, 5),
+                Interval::new(0, 1),
+                Interval::new(7, 8),
+            ]),
+            vec![
+                Interval::new(-2, 5),
+                Interval::new(7, 8),
+                Interval::new(12, 14)
+            ]
+        );
+    }
+}
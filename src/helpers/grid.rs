@@ -0,0 +1,497 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::helpers::geometry::Point2;
+use crate::helpers::parse::ParseError;
+
+/// A position in a [`Grid`] as `(x, y)`, with `(0, 0)` in the top left corner.
+pub type Pos = (usize, usize);
+
+/// Offsets of the four orthogonal neighbors: up, right, down, left.
+pub const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of all eight neighbors, clockwise starting with up.
+pub const OFFSETS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum GridError {
+    #[error("line {line} has {found} cells, expected {expected}")]
+    Ragged {
+        line: usize,
+        found: usize,
+        expected: usize,
+    },
+}
+
+impl From<GridError> for ParseError {
+    fn from(error: GridError) -> Self {
+        match error {
+            GridError::Ragged {
+                line,
+                found,
+                expected,
+            } => ParseError::new(
+                line,
+                found.min(expected) + 1,
+                format!("found {} cells, expected {}", found, expected),
+            ),
+        }
+    }
+}
+
+/// A dense, rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from cells in row order. Panics if `cells` does not fill whole rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fill rows of width {}",
+            cells.len(),
+            width
+        );
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// Parses a char map with one row per line, converting every char with `cell`.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        Self::parse_cells(input, |_, _, c| Ok(cell(c)), |error| error)
+    }
+
+    /// Like [`Grid::parse`], for chars that `cell` can reject. The error points at the char.
+    pub fn try_parse<E: Display>(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        Self::parse_cells(
+            input,
+            |line, column, c| cell(c).map_err(|e| ParseError::new(line, column, e.to_string())),
+            ParseError::from,
+        )
+    }
+
+    /// Converts every char with `cell`, which gets its line and column, starting at 1.
+    fn parse_cells<E>(
+        input: &str,
+        mut cell: impl FnMut(usize, usize, char) -> Result<T, E>,
+        ragged: impl FnOnce(GridError) -> E,
+    ) -> Result<Self, E> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        for (index, line) in input.lines().enumerate() {
+            let before = cells.len();
+            for (column, c) in line.chars().enumerate() {
+                cells.push(cell(index + 1, column + 1, c)?);
+            }
+            let found = cells.len() - before;
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(ragged(GridError::Ragged {
+                        line: index + 1,
+                        found,
+                        expected,
+                    }))
+                }
+                Some(_) => {}
+            }
+        }
+        let width = width.unwrap_or(0);
+        Ok(Grid {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// The position `offset` away from `pos`, if it is inside the grid.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// All positions in row order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| (index % width, index / width))
+    }
+
+    /// All cells with their positions in row order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell, in row order, that matches `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        let index = self.cells.iter().position(predicate)?;
+        Some((index % self.width, index / self.width))
+    }
+
+    /// The up to four orthogonal neighbors of `pos` inside the grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_4
+            .into_iter()
+            .filter_map(move |offset| self.offset(pos, offset))
+    }
+
+    /// The up to eight neighbors of `pos` inside the grid, including diagonals.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_8
+            .into_iter()
+            .filter_map(move |offset| self.offset(pos, offset))
+    }
+
+    /// The positions from `pos` in steps of `step` up to the edge of the grid, excluding `pos`.
+    pub fn ray(&self, pos: Pos, step: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.offset(pos, step), move |&pos| self.offset(pos, step))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    /// Draws the grid with one char per cell, e.g. to print it while debugging.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut rendered = String::with_capacity(self.cells.len() + self.height);
+        for row in self.rows() {
+            rendered.extend(row.iter().map(&mut cell));
+            rendered.push('\n');
+        }
+        rendered
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} is outside of the {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside of the {}x{} grid", pos, width, height),
+        }
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Grid::parse(input, |c| c)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A grid with unbounded, signed coordinates that only stores the cells that are set, e.g. for
+/// sand or rocks piling up in an open area. Use [`Point2::neighbors4`] and
+/// [`Point2::neighbors8`] to look around a cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a char map with one row per line. Chars for which `cell` returns `None` are not
+    /// stored.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Self {
+        let mut grid = SparseGrid::new();
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if let Some(value) = cell(c) {
+                    grid.insert(Point2::from((x, y)), value);
+                }
+            }
+        }
+        grid
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Point2) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Point2) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Point2) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn insert(&mut self, pos: Point2, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Point2) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    /// The stored cells with their positions, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.cells.iter().map(|(pos, cell)| (*pos, cell))
+    }
+
+    /// The smallest and largest corner of the area containing all stored cells.
+    pub fn bounds(&self) -> Option<(Point2, Point2)> {
+        let mut positions = self.cells.keys();
+        let first = *positions.next()?;
+        Some(positions.fold((first, first), |(min, max), p| {
+            (
+                Point2::new(min.x.min(p.x), min.y.min(p.y)),
+                Point2::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        }))
+    }
+
+    /// Draws the area within `bounds` with one char per cell; unset cells are passed as `None`.
+    pub fn render(&self, mut cell: impl FnMut(Option<&T>) -> char) -> String {
+        let mut rendered = String::new();
+        if let Some((min, max)) = self.bounds() {
+            for y in min.y..=max.y {
+                rendered.extend((min.x..=max.x).map(|x| cell(self.get(Point2::new(x, y)))));
+                rendered.push('\n');
+            }
+        }
+        rendered
+    }
+}
+
+impl<T> FromIterator<(Point2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<(Point2, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point2, T)>>(&mut self, iter: I) {
+        self.cells.extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "abc\ndef\n";
+
+    #[test]
+    fn test_parse_and_access() {
+        let grid: Grid<char> = MAP.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.position(|c| *c == 'e'), Some((1, 1)));
+        assert_eq!(
+            "ab\nc\n".parse::<Grid<char>>(),
+            Err(GridError::Ragged {
+                line: 2,
+                found: 1,
+                expected: 2
+            })
+        );
+        let error: ParseError = "ab\nc\n".parse::<Grid<char>>().unwrap_err().into();
+        assert_eq!(error, ParseError::new(2, 2, "found 1 cells, expected 2"));
+        let digits = Grid::parse("12\n34", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(digits.iter().map(|(_, d)| d).sum::<u32>(), 10);
+        let digit = |c: char| {
+            c.to_digit(10)
+                .ok_or(format!("expected a digit, found '{}'", c))
+        };
+        assert_eq!(Grid::try_parse("12\n34", digit), Ok(digits));
+        assert_eq!(
+            Grid::try_parse("12\n3x", digit),
+            Err(ParseError::new(2, 2, "expected a digit, found 'x'"))
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid: Grid<char> = MAP.parse().unwrap();
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors4((1, 1)).count(), 3);
+        assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_rows_columns_and_rays() {
+        let grid: Grid<char> = MAP.parse().unwrap();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(
+            grid.ray((0, 0), (1, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (2, 0)]
+        );
+        assert_eq!(grid.ray((0, 0), (-1, 0)).count(), 0);
+        assert_eq!(grid.ray((0, 1), (1, -1)).collect::<Vec<_>>(), vec![(1, 0)]);
+    }
+
+    #[test]
+    fn test_transpose_and_render() {
+        let grid: Grid<char> = MAP.parse().unwrap();
+        let transposed = grid.transpose();
+        assert_eq!(transposed.to_string(), "ad\nbe\ncf\n");
+        assert_eq!(transposed.transpose(), grid);
+        assert_eq!(
+            grid.map(|c| *c == 'e')
+                .render(|e| if *e { '#' } else { '.' }),
+            "...\n.#.\n"
+        );
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid = SparseGrid::parse("#.\n.#", |c| (c == '#').then_some(()));
+        assert_eq!(grid.len(), 2);
+        grid.insert(Point2::new(-1, 3), ());
+        grid.extend([(Point2::new(1, 1), ()), (Point2::new(0, 2), ())]);
+        assert_eq!(grid.len(), 4);
+        grid.remove(Point2::new(0, 2));
+        assert!(grid.contains(Point2::new(-1, 3)));
+        assert_eq!(grid.bounds(), Some((Point2::new(-1, 0), Point2::new(1, 3))));
+        assert_eq!(
+            grid.render(|c| if c.is_some() { '#' } else { '.' }),
+            ".#.\n..#\n...\n#..\n"
+        );
+        assert_eq!(SparseGrid::<()>::new().render(|_| '#'), "");
+    }
+}
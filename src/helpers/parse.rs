@@ -0,0 +1,282 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use regex::{Captures, Regex};
+use thiserror::Error;
+
+use crate::params::ParamError;
+
+/// Why a piece of the puzzle input could not be parsed, and where. Lines and columns start at 1
+/// and count chars, so they match what an editor shows. Errors that do not come from a position in
+/// the input, e.g. an invalid parameter, have line 0.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            return write!(f, "{}", self.message);
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl From<ParamError> for ParseError {
+    fn from(e: ParamError) -> Self {
+        ParseError::new(0, 0, e.to_string())
+    }
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// An error at `part`, which has to be a slice of `text`, e.g. a capture or a split result.
+    pub fn at(text: &str, part: &str, message: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(text.as_ptr() as usize)
+            .filter(|offset| *offset <= text.len())
+            .expect("`part` is not a slice of `text`");
+        let before = &text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError::new(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            message,
+        )
+    }
+
+    /// Moves the error down by `lines`, for errors in a part that starts further down the input.
+    pub fn below(mut self, lines: usize) -> Self {
+        if self.line > 0 {
+            self.line += lines;
+        }
+        self
+    }
+}
+
+/// Creates a [`Regex`] the first time it is used and returns the same one afterwards, so solutions
+/// do not compile their patterns again for every call of `parse`.
+///
+/// ```
+/// let re = advent_of_code::lazy_regex!(r"move (\d+) from (\d+) to (\d+)");
+/// assert!(re.is_match("move 1 from 2 to 1"));
+/// ```
+#[macro_export]
+macro_rules! lazy_regex {
+    ($pattern:expr $(,)?) => {{
+        static REGEX: ::std::sync::OnceLock<::regex::Regex> = ::std::sync::OnceLock::new();
+        REGEX.get_or_init(|| ::regex::Regex::new($pattern).unwrap())
+    }};
+}
+
+pub use crate::lazy_regex;
+
+/// Matches `regex` against `text`, with an error pointing at `text` if it does not match.
+pub fn captures<'t>(regex: &Regex, text: &'t str) -> Result<Captures<'t>, ParseError> {
+    regex
+        .captures(text)
+        .ok_or_else(|| ParseError::at(text, text, format!("expected text matching `{}`", regex)))
+}
+
+/// Parses `part` of `text`, e.g. a capture or one of the pieces of a split, as a `T`.
+pub fn value<T>(text: &str, part: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let trimmed = part.trim();
+    trimmed.parse().map_err(|e| {
+        ParseError::at(
+            text,
+            trimmed,
+            format!("invalid value \"{}\": {}", trimmed, e),
+        )
+    })
+}
+
+/// All integers in `text`, ignoring everything around them. A `-` directly in front of a number
+/// makes it negative.
+pub fn ints<T>(text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    lazy_regex!(r"-?\d+")
+        .find_iter(text)
+        .map(|number| value(text, number.as_str()))
+        .collect()
+}
+
+/// Exactly `N` integers from `text`, e.g. `[count, from, to]` from `move 1 from 2 to 1`.
+pub fn ints_array<T, const N: usize>(text: &str) -> Result<[T; N], ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let found = ints(text)?;
+    let count = found.len();
+    found.try_into().map_err(|_| {
+        ParseError::at(
+            text,
+            text,
+            format!("expected {} numbers, found {}", N, count),
+        )
+    })
+}
+
+/// Splits `input` into blocks separated by empty lines, with the line number each block starts
+/// on.
+pub fn paragraphs(input: &str) -> impl Iterator<Item = (usize, &str)> + '_ {
+    let mut line = 1;
+    let mut counted = 0;
+    lazy_regex!(r"\r?\n(?:[ \t]*\r?\n)+")
+        .split(input.trim_end())
+        .map(move |paragraph| {
+            let offset = paragraph.as_ptr() as usize - input.as_ptr() as usize;
+            line += input[counted..offset].matches('\n').count();
+            counted = offset;
+            (line, paragraph)
+        })
+}
+
+/// Parses every paragraph of `input` with `parse`. Errors are reported relative to `input`.
+pub fn parse_paragraphs<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    paragraphs(input)
+        .map(|(line, paragraph)| parse(paragraph).map_err(|e| e.below(line - 1)))
+        .collect()
+}
+
+/// Parses every non-empty line of `input` with `parse`. Errors are reported relative to `input`.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parse(line).map_err(|e| e.below(index)))
+        .collect()
+}
+
+/// Splits `line` at the first `separator` into a trimmed key and value, e.g.
+/// `Starting items: 79, 98` into `Starting items` and `79, 98`.
+pub fn key_value<'a>(line: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    match line.split_once(separator) {
+        Some((key, value)) => Ok((key.trim(), value.trim())),
+        None => Err(ParseError::at(
+            line,
+            line.trim_start(),
+            format!("expected `key{}value`", separator),
+        )),
+    }
+}
+
+/// [`key_value`] for every non-empty line of `input`.
+pub fn key_values<'a>(
+    input: &'a str,
+    separator: &str,
+) -> Result<Vec<(&'a str, &'a str)>, ParseError> {
+    parse_lines(input, |line| key_value(line, separator))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_position() {
+        let text = "move 1\nfrom 2 to x";
+        let error = ParseError::at(text, &text[17..], "expected a number");
+        assert_eq!(error, ParseError::new(2, 11, "expected a number"));
+        assert_eq!(error.to_string(), "line 2, column 11: expected a number");
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(
+            ints::<i64>("Sensor at x=2, y=-18: beacon at x=-2, y=15"),
+            Ok(vec![2, -18, -2, 15])
+        );
+        assert_eq!(
+            ints_array::<usize, 3>("move 13 from 2 to 1"),
+            Ok([13, 2, 1])
+        );
+        assert_eq!(
+            ints_array::<usize, 3>("move 13 from 2"),
+            Err(ParseError::new(1, 1, "expected 3 numbers, found 2"))
+        );
+        let error = ints::<u8>("a=1, b=-2").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 8));
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let input = "a\nb\n\n\nc\r\n\r\nd\n";
+        assert_eq!(
+            paragraphs(input).collect::<Vec<_>>(),
+            vec![(1, "a\nb"), (5, "c"), (7, "d")]
+        );
+        let error = parse_paragraphs(input, |p| match p {
+            "c" => Err(ParseError::at(p, p, "bad")),
+            _ => Ok(()),
+        });
+        assert_eq!(error, Err(ParseError::new(5, 1, "bad")));
+    }
+
+    #[test]
+    fn test_key_values() {
+        assert_eq!(
+            key_values(
+                "  Starting items: 79, 98\n  Operation: new = old * 19\n",
+                ":"
+            ),
+            Ok(vec![
+                ("Starting items", "79, 98"),
+                ("Operation", "new = old * 19")
+            ])
+        );
+        assert_eq!(
+            key_values("a: 1\n  b 2", ":"),
+            Err(ParseError::new(2, 3, "expected `key:value`"))
+        );
+    }
+
+    #[test]
+    fn test_regex() {
+        let re = lazy_regex!(r"x=(-?\d+)");
+        let line = "at x=-3";
+        let caps = captures(re, line).unwrap();
+        assert_eq!(value::<i32>(line, caps.get(1).unwrap().as_str()), Ok(-3));
+        assert_eq!(
+            captures(re, "at y=1").unwrap_err().message,
+            r"expected text matching `x=(-?\d+)`"
+        );
+        let line = "count: 1x";
+        assert_eq!(
+            value::<u32>(line, &line[7..]),
+            Err(ParseError::new(
+                1,
+                8,
+                "invalid value \"1x\": invalid digit found in string"
+            ))
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A goal reached by a search, with the cost of the cheapest way there. The search keeps track of
+/// how it reached every node, so the path itself is only built when asked for.
+#[derive(Debug, Clone)]
+pub struct Found<N, C> {
+    pub goal: N,
+    pub cost: C,
+    parents: HashMap<N, N>,
+}
+
+impl<N: Eq + Hash + Clone, C> Found<N, C> {
+    /// The nodes from the start to the goal, both included.
+    pub fn path(&self) -> Vec<N> {
+        let mut path = vec![self.goal.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth-first search from `start` to the first node that `is_goal` accepts. The cost is the
+/// number of steps.
+pub fn bfs<N, S, I>(
+    start: N,
+    successors: S,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    bfs_multi([start], successors, is_goal)
+}
+
+/// Breadth-first search from several start nodes at once, e.g. "the shortest path from any `a`".
+/// The path of the result begins at the closest start.
+pub fn bfs_multi<N, S, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: S,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents = HashMap::new();
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if seen.insert(start.clone()) {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, cost)) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(Found {
+                goal: node,
+                cost,
+                parents,
+            });
+        }
+        for next in successors(&node) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), node.clone());
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+    None
+}
+
+/// The number of steps from `start` to every node reachable from it.
+pub fn bfs_distances<N, S, I>(start: N, mut successors: S) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, cost)) = queue.pop_front() {
+        for next in successors(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), cost + 1);
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// Cheapest path from `start` to a node that `is_goal` accepts. `successors` returns the
+/// neighbors of a node with the cost of the step to them, which must not be negative.
+pub fn dijkstra<N, C, S, I>(
+    start: N,
+    successors: S,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], guided by `heuristic`, an estimate of the remaining cost to a goal. The
+/// result is only the cheapest path if the heuristic never overestimates, e.g. the Manhattan
+/// distance on a grid.
+pub fn astar<N, C, S, I>(
+    start: N,
+    mut successors: S,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // Nodes are kept in `nodes` and referenced by index, so the heap does not need `N: Ord`.
+    let mut nodes = vec![start.clone()];
+    let mut costs = HashMap::from([(start.clone(), (C::default(), 0))]);
+    let mut parents = HashMap::new();
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if costs[&node].0 < cost {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(Found {
+                goal: node,
+                cost,
+                parents,
+            });
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            let index = match costs.get(&next) {
+                Some(&(known, _)) if known <= next_cost => continue,
+                Some(&(_, index)) => index,
+                None => {
+                    nodes.push(next.clone());
+                    nodes.len() - 1
+                }
+            };
+            costs.insert(next.clone(), (next_cost, index));
+            parents.insert(next.clone(), node.clone());
+            heap.push(Reverse((next_cost + heuristic(&next), next_cost, index)));
+        }
+    }
+    None
+}
+
+/// Cheapest costs between all pairs of `nodes` (Floyd–Warshall). Pairs without a path are left
+/// out, and every node reaches itself at the default cost.
+pub fn all_pairs<N, C, S, I>(nodes: &[N], mut successors: S) -> HashMap<(N, N), C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let index: HashMap<&N, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+    let mut costs: Vec<Vec<Option<C>>> = vec![vec![None; nodes.len()]; nodes.len()];
+    for (i, node) in nodes.iter().enumerate() {
+        costs[i][i] = Some(C::default());
+        for (next, step) in successors(node) {
+            if let Some(&j) = index.get(&next) {
+                costs[i][j] = Some(costs[i][j].map_or(step, |known| known.min(step)));
+            }
+        }
+    }
+
+    for k in 0..nodes.len() {
+        let via_k = costs[k].clone();
+        for row in costs.iter_mut() {
+            let Some(ik) = row[k] else { continue };
+            for (cost, kj) in row.iter_mut().zip(&via_k) {
+                if let Some(kj) = kj {
+                    let through = ik + *kj;
+                    if cost.is_none_or(|known| through < known) {
+                        *cost = Some(through);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut pairs = HashMap::new();
+    for (i, from) in nodes.iter().enumerate() {
+        for (j, to) in nodes.iter().enumerate() {
+            if let Some(cost) = costs[i][j] {
+                pairs.insert((from.clone(), to.clone()), cost);
+            }
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small weighted graph: 0 -> 1 (7), 0 -> 2 (2), 2 -> 1 (3), 1 -> 3 (1), 4 is unreachable.
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 7), (2, 2)],
+            1 => vec![(3, 1)],
+            2 => vec![(1, 3)],
+            4 => vec![(0, 1)],
+            _ => vec![],
+        }
+    }
+
+    fn neighbors(node: &u32) -> Vec<u32> {
+        edges(node).into_iter().map(|(next, _)| next).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let found = bfs(0, neighbors, |n| *n == 3).unwrap();
+        assert_eq!(found.cost, 2);
+        assert_eq!(found.path(), vec![0, 1, 3]);
+        assert!(bfs(0, neighbors, |n| *n == 4).is_none());
+
+        let found = bfs_multi([2, 4], neighbors, |n| *n == 1).unwrap();
+        assert_eq!((found.cost, found.path()), (1, vec![2, 1]));
+
+        let distances = bfs_distances(0, neighbors);
+        assert_eq!(distances[&3], 2);
+        assert_eq!(distances.len(), 4);
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        let found = dijkstra(0, edges, |n| *n == 3).unwrap();
+        assert_eq!(found.cost, 6);
+        assert_eq!(found.path(), vec![0, 2, 1, 3]);
+
+        // Steps on a line from 0 to 10, where every node can also jump 3 ahead at cost 2.
+        let line = |n: &i32| vec![(n + 1, 1), (n + 3, 2)];
+        let found = astar(0, line, |n| (10 - n).max(0) / 3, |n| *n == 10).unwrap();
+        assert_eq!(found.cost, 7);
+        // 0, 3, 6, 9, 10 and 0, 1, 4, 7, 10 are both cheapest.
+        let path = found.path();
+        assert_eq!((path.len(), path[0], path[4]), (5, 0, 10));
+        assert!(dijkstra(3, edges, |n| *n == 0).is_none());
+    }
+
+    #[test]
+    fn test_all_pairs() {
+        let costs = all_pairs(&[0, 1, 2, 3, 4], edges);
+        assert_eq!(costs[&(0, 3)], 6);
+        assert_eq!(costs[&(4, 1)], 6);
+        assert_eq!(costs[&(2, 2)], 0);
+        assert!(!costs.contains_key(&(3, 0)));
+    }
+}
@@ -3,92 +3,363 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
-use std::env;
+use std::fmt::{self, Display};
 use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
 
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::cli::Parts;
+use crate::helpers::parse::ParseError;
+use crate::params::{Param, ParamError, Params};
+
+pub mod answers;
+pub mod aoc;
+pub mod bench;
+pub mod cli;
+pub mod examples;
 pub mod helpers;
+pub mod params;
+pub mod props;
+pub mod puzzle;
+pub mod readme;
+pub mod report;
+pub mod solutions;
+pub mod template;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
+/// A puzzle solution. `parse` turns the raw puzzle input into `Input`, which both parts then
+/// work on. Parts return `None` while they are not solved yet.
+///
+/// Malformed input is reported as a [`ParseError`] pointing at the offending line and column,
+/// see [`helpers::parse`] for functions that build them.
+///
+/// Constants that differ between the example and the real input are declared in `PARAMS`.
+/// Such solutions implement `parse_with` and keep the values they need in `Input`; their `parse`
+/// calls `parse_with` with `Params::defaults(Self::PARAMS)`. Values that `parse_with` cannot work
+/// with are rejected in `check_params`, which runs whenever parameters are overridden;
+/// `parse_with` reads them with `Params::try_get` and returns the error instead of panicking.
+pub trait Solution {
+    type Input;
+
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+        Self::parse(input)
+    }
+    fn check_params(_params: &Params) -> Result<(), ParamError> {
+        Ok(())
+    }
+    fn part_one(input: &Self::Input) -> Option<Answer>;
+    fn part_two(input: &Self::Input) -> Option<Answer>;
+}
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i128),
+    /// Answers that are not numbers, e.g. crate labels or multi-line CRT output.
+    Text(String),
+}
+
+macro_rules! impl_answer_from_int {
+    ($($int:ty),*) => {$(
+        impl From<$int> for Answer {
+            fn from(value: $int) -> Self {
+                Answer::Number(value as i128)
+            }
+        }
+    )*};
+}
+
+impl_answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl<'de> Visitor<'de> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an integer or a string")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Answer, E> {
+                Ok(value.into())
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Answer, E> {
+                Ok(value.into())
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Answer, E> {
+                Ok(value.into())
             }
         }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+#[macro_export]
+macro_rules! solve {
+    ($solution:ty, $year:expr, $day:expr) => {{
+        advent_of_code::solve_day::<$solution>($year, $day);
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// The outcome of running one part of a solution.
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub elapsed: Duration,
+}
+
+/// The outcome of running a solution: the time spent parsing plus the selected parts.
+pub struct DayResult {
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartResult>,
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+impl DayResult {
+    pub fn total_elapsed(&self) -> Duration {
+        self.parts
+            .iter()
+            .fold(self.parse_elapsed, |total, part| total + part.elapsed)
+    }
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+fn timed<T>(func: impl FnOnce() -> T) -> (T, Duration) {
+    let timer = Instant::now();
+    let result = func();
+    (result, timer.elapsed())
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Parses `input` once and runs the selected parts of `S` on it, timing each step separately.
+pub fn run<S: Solution>(input: &str, parts: Parts) -> Result<DayResult, ParseError> {
+    run_with::<S>(input, parts, &Params::defaults(S::PARAMS))
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
+/// Like `run`, with parameter values other than the defaults.
+pub fn run_with<S: Solution>(
+    input: &str,
+    parts: Parts,
+    params: &Params,
+) -> Result<DayResult, ParseError> {
+    let (parsed, parse_elapsed) = timed(|| S::parse_with(input, params));
+    let parsed = parsed?;
+    let parts = [(1, S::part_one as fn(&S::Input) -> _), (2, S::part_two)]
+        .into_iter()
+        .filter(|(part, _)| parts.includes(*part))
+        .map(|(part, func)| {
+            let (answer, elapsed) = timed(|| func(&parsed));
+            PartResult {
+                part,
+                answer,
+                elapsed,
             }
-        }
+        })
+        .collect();
+    Ok(DayResult {
+        parse_elapsed,
+        parts,
     })
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+pub fn print_day(result: &DayResult) {
+    print!("{}", format_day(result));
+}
+
+/// The output of [`print_day`], for callers that print it later.
+pub fn format_day(result: &DayResult) -> String {
+    let mut output = format!(
+        "{}Parse (elapsed: {:.2?}){}\n",
+        ANSI_ITALIC, result.parse_elapsed, ANSI_RESET
+    );
+    for result in &result.parts {
+        output.push_str(&format!(
+            "🎄 {}Part {}{} 🎄\n",
+            ANSI_BOLD, result.part, ANSI_RESET
+        ));
+        output.push_str(&format_result(result.answer.as_ref(), result.elapsed));
+        output.push('\n');
+    }
+    output
+}
+
+struct DayArgs {
+    input: Option<String>,
+    parts: Parts,
+    params: Vec<(String, String)>,
+}
+
+fn parse_day_args() -> Result<DayArgs, cli::ArgsError> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = DayArgs {
+        input: args.opt_value_from_str("--input")?,
+        parts: args.opt_value_from_str("--part")?.unwrap_or_default(),
+        params: args.values_from_fn("--param", params::parse_assignment)?,
+    };
+    cli::finish(args)?;
+    Ok(parsed)
+}
+
+/// Entry point of the day binaries. Reads `src/inputs/YYYY/NN.txt`, or the file passed with
+/// `--input <path>` (`-` reads stdin), and runs the parts selected with `--part`. Parameters
+/// are overridden with `--param name=value`.
+pub fn solve_day<S: Solution>(year: u16, day: u8) {
+    let args = match parse_day_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    let params = Params::with_overrides(S::PARAMS, args.params)
+        .and_then(|params| S::check_params(&params).map(|_| params));
+    let params = match params {
+        Ok(params) => params,
+        Err(e) => {
+            eprintln!("Day {:02}: {}", day, e);
+            process::exit(1);
+        }
+    };
+
+    let input = match args.input.as_deref() {
+        None => read_file("inputs", year, day).map_err(|e| e.describe_input(year, day)),
+        Some("-") => read_stdin().map_err(|e| e.to_string()),
+        Some(path) => read_path(Path::new(path)).map_err(|e| e.to_string()),
+    };
+
+    let result = input.and_then(|input| {
+        run_with::<S>(&input, args.parts, &params).map_err(|e| format!("invalid input, {}", e))
+    });
+    match result {
+        Ok(result) => print_day(&result),
+        Err(e) => {
+            eprintln!("Day {:02}: {}", day, e);
+            process::exit(1);
+        }
+    }
+}
+
+pub fn print_result<T: Display>(result: Option<T>, elapsed: Duration) {
+    println!("{}", format_result(result, elapsed));
+}
+
+fn format_result<T: Display>(result: Option<T>, elapsed: Duration) -> String {
+    match result {
+        Some(result) => format!(
+            "{} {}(elapsed: {:.2?}){}",
+            result, ANSI_ITALIC, elapsed, ANSI_RESET
+        ),
+        None => "not solved.".to_string(),
+    }
+}
+
+/// Why an input or example file could not be read.
+#[derive(Debug, Error)]
+pub enum ReadError {
+    #[error("\"{}\" does not exist", .0.display())]
+    Missing(PathBuf),
+    #[error("\"{}\" is empty", .0.display())]
+    Empty(PathBuf),
+    #[error("\"{}\" is not valid UTF-8", .0.display())]
+    NotUtf8(PathBuf),
+    #[error("could not read \"{}\": {}", .0.display(), .1)]
+    Io(PathBuf, #[source] io::Error),
+}
+
+impl ReadError {
+    /// A message for a failed read of the puzzle input of `day`, pointing at `cargo download`
+    /// if the input has not been downloaded yet.
+    pub fn describe_input(&self, year: u16, day: u8) -> String {
+        match self {
+            ReadError::Missing(_) | ReadError::Empty(_) if year == aoc::YEAR => {
+                format!("input missing, run `cargo download {}`", day)
+            }
+            ReadError::Missing(_) | ReadError::Empty(_) => {
+                format!(
+                    "input missing, run `cargo download {} --year {}`",
+                    day, year
+                )
+            }
+            _ => self.to_string(),
+        }
+    }
+}
+
+/// Path of the file for `day` of `year` in `src/<folder>/<year>`. Paths are resolved against the
+/// crate root, so binaries and tests find their files regardless of the working directory.
+pub fn data_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join(folder)
+        .join(year.to_string())
+        .join(format!("{:02}.txt", day))
+}
+
+pub fn read_file(folder: &str, year: u16, day: u8) -> Result<String, ReadError> {
+    read_path(&data_path(folder, year, day))
+}
+
+pub fn read_path(path: &Path) -> Result<String, ReadError> {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Err(ReadError::Missing(path.to_path_buf()))
+        }
+        Err(e) => return Err(ReadError::Io(path.to_path_buf(), e)),
+    };
+    to_input(path, bytes)
+}
+
+fn read_stdin() -> Result<String, ReadError> {
+    let path = Path::new("<stdin>");
+    let mut bytes = Vec::new();
+    match io::stdin().read_to_end(&mut bytes) {
+        Ok(_) => to_input(path, bytes),
+        Err(e) => Err(ReadError::Io(path.to_path_buf(), e)),
+    }
+}
+
+fn to_input(path: &Path, bytes: Vec<u8>) -> Result<String, ReadError> {
+    if bytes.is_empty() {
+        return Err(ReadError::Empty(path.to_path_buf()));
+    }
+    String::from_utf8(bytes).map_err(|_| ReadError::NotUtf8(path.to_path_buf()))
 }
 
 #[cfg(test)]
@@ -96,30 +367,86 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
+    fn test_read_file() {
+        assert!(read_file("examples", 2022, 1).unwrap().starts_with("1000"));
+        assert!(matches!(
+            read_file("examples", 2022, 99),
+            Err(ReadError::Missing(_))
+        ));
+        assert!(matches!(
+            read_file("examples", 2021, 1),
+            Err(ReadError::Missing(_))
+        ));
+        let error = ReadError::Empty(data_path("inputs", 2022, 3));
+        assert_eq!(
+            error.describe_input(2022, 3),
+            "input missing, run `cargo download 3`"
+        );
+        assert_eq!(
+            error.describe_input(2021, 3),
+            "input missing, run `cargo download 3 --year 2021`"
+        );
+    }
+
+    #[test]
+    fn test_run_invalid_input() {
+        use crate::solutions::y2022::{day05::Day05, day17::Day17};
+
+        let input = "[A]\n 1 \n\nmove 1 from 1 to 1\n";
+        assert!(run::<Day05>(input, Parts::Both).is_ok());
+        let input = format!("{}move 1 from 1\n", input);
+        assert_eq!(
+            run::<Day05>(&input, Parts::Both).err(),
+            Some(ParseError::new(5, 1, "expected 3 numbers, found 2"))
         );
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
+        assert_eq!(
+            run::<Day17>("\n", Parts::Both).err(),
+            Some(ParseError::new(
+                1,
+                1,
+                "expected a jet pattern of `<` and `>`"
+            ))
+        );
+        assert_eq!(
+            run::<Day17>("<>x<\n", Parts::Both).err(),
+            Some(ParseError::new(1, 3, "expected `<` or `>`, found 'x'"))
         );
+    }
+
+    #[test]
+    fn test_check_params() {
+        use crate::solutions::y2022::{
+            day07::Day07, day11::Day11, day14::Day14, day15::Day15, day16::Day16, day17::Day17,
+        };
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
+        let width = |value| Params::with_overrides(Day17::PARAMS, [("width", value)]).unwrap();
+        assert_eq!(Day17::check_params(&width("6")), Ok(()));
+        assert_eq!(
+            Day17::check_params(&width("3")).unwrap_err().to_string(),
+            "invalid value \"3\" for parameter `width`: \
+             the chamber has to be at least 6 wide to fit every rock where it appears"
         );
+        let source = Params::with_overrides(Day14::PARAMS, [("source", "500")]).unwrap();
+        assert!(matches!(
+            Day14::check_params(&source),
+            Err(ParamError::Invalid { name, .. }) if name == "source"
+        ));
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+        let rounds = Params::with_overrides(Day11::PARAMS, [("rounds", "-5")]).unwrap();
+        assert!(Day11::check_params(&rounds).is_err());
+        assert_eq!(
+            Day11::parse_with(include_str!("examples/2022/11.txt"), &rounds)
+                .err()
+                .map(|e| e.to_string())
+                .as_deref(),
+            Some("invalid value \"-5\" for parameter `rounds`: invalid digit found in string")
         );
+        let disk = Params::with_overrides(Day07::PARAMS, [("disk_size", "10")]).unwrap();
+        assert!(Day07::check_params(&disk).is_err());
+        let row = Params::with_overrides(Day15::PARAMS, [("row", "abc")]).unwrap();
+        assert!(Day15::check_params(&row).is_err());
+        let minutes = Params::with_overrides(Day16::PARAMS, [("minutes", "-1")]).unwrap();
+        assert!(Day16::check_params(&minutes).is_err());
     }
 }
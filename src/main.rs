@@ -2,45 +2,265 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::aoc;
+use advent_of_code::bench::{self, Baseline, DayBench};
+use advent_of_code::cli::{self, Days, Parts};
+use advent_of_code::helpers::parse::ParseError;
+use advent_of_code::report::{Format, Record};
+use advent_of_code::{solutions, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+const USAGE: &str = "Usage: cargo all [--year <year>] [--days <days>] [--part <1|2>] [--jobs <n>] \
+                     [--format text|json] [--bench [--save-baseline] [--baseline <path>]]";
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+struct Args {
+    bench: bool,
+    save_baseline: bool,
+    baseline: Option<PathBuf>,
+    format: Format,
+    year: u16,
+    days: Days,
+    parts: Parts,
+    jobs: usize,
+}
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+fn parse_args() -> Result<Args, cli::ArgsError> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        bench: args.contains("--bench"),
+        save_baseline: args.contains("--save-baseline"),
+        baseline: args.opt_value_from_str("--baseline")?,
+        format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(aoc::YEAR),
+        days: args.opt_value_from_str("--days")?.unwrap_or_default(),
+        parts: args.opt_value_from_str("--part")?.unwrap_or_default(),
+        jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+    };
+    cli::finish(args)?;
+    Ok(parsed)
+}
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+/// Everything one day adds to the run. Days collect their output instead of printing it, so
+/// that parallel days can still be printed in order.
+#[derive(Default)]
+struct DayOutput {
+    day: u8,
+    text: String,
+    records: Vec<Record>,
+    total: Duration,
+    bench: Option<DayBench>,
+}
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+fn run_day(args: &Args, baseline: Option<&Baseline>, day: u8) -> DayOutput {
+    let is_text = args.format == Format::Text;
+    let year = args.year;
+    let mut output = DayOutput {
+        day,
+        ..DayOutput::default()
+    };
+    if is_text {
+        output.text.push_str("----------\n");
+        output
+            .text
+            .push_str(&format!("{}| Day {:02} |{}\n", ANSI_BOLD, day, ANSI_RESET));
+        output.text.push_str("----------\n");
+    }
+
+    let solution = match solutions::get(year, day) {
+        Some(solution) => solution,
+        None => {
+            if is_text {
+                output.text.push_str("Not solved.\n");
+            }
+            output
+                .records
+                .extend(Record::unsolved(year, day, args.parts));
+            return output;
+        }
+    };
+
+    let input = match advent_of_code::read_file("inputs", year, day) {
+        Ok(input) => input,
+        Err(e) => {
+            if is_text {
+                output.text.push_str(&e.describe_input(year, day));
+                output.text.push('\n');
+            }
+            output
+                .records
+                .extend(Record::missing_input(year, day, args.parts));
+            return output;
+        }
+    };
+
+    let invalid_input = |mut output: DayOutput, e: ParseError| {
+        if is_text {
+            output.text.push_str(&format!("Invalid input, {}\n", e));
+        }
+        output
+            .records
+            .extend(Record::invalid_input(year, day, args.parts));
+        output
+    };
+
+    if args.bench {
+        let result = match (solution.bench)(&input, args.parts) {
+            Ok(result) => result,
+            Err(e) => return invalid_input(output, e),
+        };
+        if is_text {
+            let day_baseline = baseline.and_then(|b| b.get(&day));
+            output
+                .text
+                .push_str(&bench::format_day(&result, day_baseline));
+        }
+        output.total = result.total_median();
+        for part in &result.parts {
+            let median = part.stats.map(|stats| stats.median);
+            let record = Record::new(year, day, part.part, part.answer.clone(), median);
+            output.records.push(record);
+        }
+        output.bench = Some(result);
+    } else {
+        let result = match (solution.run)(&input, args.parts) {
+            Ok(result) => result,
+            Err(e) => return invalid_input(output, e),
+        };
+        if is_text {
+            output.text.push_str(&advent_of_code::format_day(&result));
+        }
+        output.total = result.total_elapsed();
+        for part in result.parts {
+            let elapsed = Some(part.elapsed);
+            let record = Record::new(year, day, part.part, part.answer, elapsed);
+            output.records.push(record);
+        }
+    }
+    output
+}
+
+/// Runs `days` on `args.jobs` threads. The output of every day is passed to `handle` in day
+/// order, as soon as all days before it are done.
+fn run_parallel(
+    args: &Args,
+    baseline: Option<&Baseline>,
+    days: &[u8],
+    mut handle: impl FnMut(DayOutput),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..args.jobs.min(days.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(index) else { break };
+                if sender.send((index, run_day(args, baseline, day))).is_err() {
+                    break;
                 }
-            );
+            });
+        }
+        drop(sender);
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+        let mut finished = BTreeMap::new();
+        let mut printed = 0;
+        for (index, output) in receiver {
+            finished.insert(index, output);
+            while let Some(output) = finished.remove(&printed) {
+                handle(output);
+                printed += 1;
             }
-        })
-        .sum();
+        }
+    });
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    };
+    if args.jobs == 0 {
+        eprintln!("`--jobs` needs to be at least 1.");
+        process::exit(1);
+    }
+    if args.bench && args.jobs > 1 {
+        eprintln!("Benchmarks run one day at a time, so that days running in parallel do not skew the timings. Remove `--jobs` to benchmark.");
+        process::exit(1);
+    }
+
+    let year = args.year;
+    let baseline_path = &args
+        .baseline
+        .clone()
+        .unwrap_or_else(|| bench::baseline_path(year));
+    let baseline = if args.bench {
+        bench::load_baseline(baseline_path)
+    } else {
+        None
+    };
+    let mut new_baseline = baseline.clone().unwrap_or_else(Baseline::new);
+    let mut records = Vec::new();
+    let mut total = Duration::ZERO;
+
+    let mut handle = |output: DayOutput| {
+        print!("{}", output.text);
+        records.extend(output.records);
+        total += output.total;
+        if let Some(result) = output.bench {
+            new_baseline.entry(output.day).or_default().update(&result);
+        }
+    };
+    let days: Vec<u8> = args.days.iter().collect();
+    if args.jobs == 1 {
+        for &day in &days {
+            handle(run_day(&args, baseline.as_ref(), day));
+        }
+    } else {
+        run_parallel(&args, baseline.as_ref(), &days, handle);
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+    let timing_note = format!(
+        "Timings were measured with up to {} days running in parallel and are not suitable for comparison.",
+        args.jobs
     );
+    match args.format {
+        Format::Text => {
+            println!(
+                "{}Total:{} {}{:.2?}{}",
+                ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+            );
+            if args.jobs > 1 {
+                println!("{}{}{}", ANSI_ITALIC, timing_note, ANSI_RESET);
+            }
+        }
+        Format::Json => {
+            println!("{}", serde_json::to_string_pretty(&records).unwrap());
+            if args.jobs > 1 {
+                eprintln!("{}", timing_note);
+            }
+        }
+    }
+
+    if args.bench && args.save_baseline {
+        match bench::save_baseline(baseline_path, &new_baseline) {
+            Ok(_) => eprintln!("Saved baseline to \"{}\".", baseline_path.display()),
+            Err(e) => {
+                eprintln!("Failed to save baseline: {}", e);
+                process::exit(1);
+            }
+        }
+    }
 }
@@ -0,0 +1,187 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// A named puzzle parameter, e.g. a row that differs between the example and the real input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub description: &'static str,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParamError {
+    #[error("expected `name=value`, got \"{0}\"")]
+    Syntax(String),
+    #[error("unknown parameter `{0}`, {1}")]
+    Unknown(String, String),
+    #[error("invalid value \"{value}\" for parameter `{name}`: {reason}")]
+    Invalid {
+        name: String,
+        value: String,
+        reason: String,
+    },
+}
+
+impl ParamError {
+    pub fn invalid(name: &str, value: &str, reason: impl Display) -> Self {
+        ParamError::Invalid {
+            name: name.to_string(),
+            value: value.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// Lists the declared parameters and their defaults, e.g. when an unknown one is overridden.
+pub fn describe(declared: &[Param]) -> String {
+    if declared.is_empty() {
+        return "this solution does not take parameters".to_string();
+    }
+    let params: Vec<String> = declared
+        .iter()
+        .map(|p| format!("{} (default {}): {}", p.name, p.default, p.description))
+        .collect();
+    format!("available parameters: {}", params.join(", "))
+}
+
+/// Splits a `name=value` assignment from the command line.
+pub fn parse_assignment(assignment: &str) -> Result<(String, String), ParamError> {
+    match assignment.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(ParamError::Syntax(assignment.to_string())),
+    }
+}
+
+/// The parameter values for one run of a solution: the declared defaults, with overrides from the
+/// command line or an examples manifest applied.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<&'static str, String>,
+}
+
+impl Params {
+    pub fn defaults(declared: &'static [Param]) -> Self {
+        Params {
+            values: declared
+                .iter()
+                .map(|p| (p.name, p.default.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Applies `overrides` to the defaults. Overriding a parameter that is not declared is an
+    /// error, so a typo does not silently run with the default.
+    pub fn with_overrides<N, V>(
+        declared: &'static [Param],
+        overrides: impl IntoIterator<Item = (N, V)>,
+    ) -> Result<Self, ParamError>
+    where
+        N: AsRef<str>,
+        V: Into<String>,
+    {
+        let mut params = Params::defaults(declared);
+        for (name, value) in overrides {
+            let name = name.as_ref();
+            match declared.iter().find(|p| p.name == name) {
+                Some(param) => params.values.insert(param.name, value.into()),
+                None => return Err(ParamError::Unknown(name.to_string(), describe(declared))),
+            };
+        }
+        Ok(params)
+    }
+
+    /// The raw value of a declared parameter.
+    pub fn get_str(&self, name: &str) -> &str {
+        match self.values.get(name) {
+            Some(value) => value,
+            None => panic!("parameter `{}` is not declared in `PARAMS`", name),
+        }
+    }
+
+    /// The value of a declared parameter, parsed as `T`.
+    pub fn try_get<T>(&self, name: &str) -> Result<T, ParamError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = self.get_str(name);
+        value
+            .parse()
+            .map_err(|e| ParamError::invalid(name, value, e))
+    }
+
+    /// Like `try_get`, but panics if the value is not a valid `T`. Solutions use `try_get`, this is
+    /// for code that builds its own parameters, e.g. tests and reference implementations.
+    pub fn get<T>(&self, name: &str) -> T
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.try_get(name).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: &[Param] = &[
+        Param {
+            name: "row",
+            default: "2000000",
+            description: "the row to count",
+        },
+        Param {
+            name: "start",
+            default: "AA",
+            description: "the starting valve",
+        },
+    ];
+
+    #[test]
+    fn test_overrides() {
+        let params = Params::with_overrides(PARAMS, [("row", "10")]).unwrap();
+        assert_eq!(params.get::<i64>("row"), 10);
+        assert_eq!(params.get_str("start"), "AA");
+        assert_eq!(Params::defaults(PARAMS).get::<u32>("row"), 2_000_000);
+        assert!(matches!(
+            Params::with_overrides(PARAMS, [("rows", "10")]),
+            Err(ParamError::Unknown(name, _)) if name == "rows"
+        ));
+        let params = Params::with_overrides(PARAMS, [("row", "ten")]).unwrap();
+        assert_eq!(
+            params.try_get::<i64>("row").unwrap_err().to_string(),
+            "invalid value \"ten\" for parameter `row`: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_parse_assignment() {
+        assert_eq!(
+            parse_assignment("row = 10"),
+            Ok(("row".to_string(), "10".to_string()))
+        );
+        assert_eq!(
+            parse_assignment("source=500,0"),
+            Ok(("source".to_string(), "500,0".to_string()))
+        );
+        assert_eq!(
+            parse_assignment("=10"),
+            Err(ParamError::Syntax("=10".to_string()))
+        );
+        assert_eq!(
+            parse_assignment("row"),
+            Err(ParamError::Syntax("row".to_string()))
+        );
+    }
+}
@@ -0,0 +1,280 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::any::Any;
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+
+use thiserror::Error;
+
+use crate::helpers::parse::ParseError;
+use crate::params::Params;
+use crate::{Answer, Solution};
+
+/// A small seedable random number generator (SplitMix64). Every generated input is determined by
+/// its seed, so a failing case can be reproduced from the seed in the error.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, both ends included.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {}..={}", start, end);
+        let size = end.abs_diff(start) + 1;
+        start.wrapping_add((self.next_u64() % size) as i64)
+    }
+
+    /// A number from 0 up to, but not including, `bound`, e.g. for lengths and indices.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "empty range 0..0");
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// `true` with a probability of `percent` in 100.
+    pub fn chance(&mut self, percent: u8) -> bool {
+        self.below(100) < percent as usize
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+/// Generates random puzzle inputs that follow the format of the real input, for checking
+/// invariants of a solution on more than the examples.
+pub trait Generate: Solution {
+    /// Overrides of `PARAMS` that generated inputs are solved with, e.g. a search area small
+    /// enough for the reference.
+    const GENERATED_PARAMS: &'static [(&'static str, &'static str)] = &[];
+
+    /// A well-formed input, small enough for the [`Reference`] implementation if there is one.
+    fn generate(rng: &mut Rng) -> String;
+}
+
+/// A slow but obviously correct implementation of a solution, to compare the optimized one
+/// against. It works on the raw input, so that mistakes in `parse` show up as well.
+pub trait Reference: Solution {
+    fn reference_one(input: &str, params: &Params) -> Option<Answer>;
+    fn reference_two(input: &str, params: &Params) -> Option<Answer>;
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PropertyError {
+    #[error("case with seed {seed} panicked: {message}\ninput:\n{input}")]
+    Panic {
+        seed: u64,
+        input: String,
+        message: String,
+    },
+    #[error("case with seed {seed} did not parse: {error}\ninput:\n{input}")]
+    Parse {
+        seed: u64,
+        input: String,
+        error: ParseError,
+    },
+    #[error("case with seed {seed}, part {part}: the reference returned {expected}, the solution {actual}\ninput:\n{input}")]
+    Mismatch {
+        seed: u64,
+        part: u8,
+        input: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
+
+fn describe(answer: &Option<Answer>) -> String {
+    answer
+        .as_ref()
+        .map_or("nothing".to_string(), |a| a.to_string())
+}
+
+/// The parameters generated inputs for `S` are solved with.
+pub fn generated_params<S: Generate>() -> Params {
+    Params::with_overrides(S::PARAMS, S::GENERATED_PARAMS.iter().copied())
+        .and_then(|params| S::check_params(&params).map(|_| params))
+        .unwrap_or_else(|e| panic!("invalid `GENERATED_PARAMS`: {}", e))
+}
+
+/// Parses a generated input. Generated inputs are well-formed, so an error is a failure.
+fn parse_generated<S: Generate>(
+    seed: u64,
+    input: &str,
+    params: &Params,
+) -> Result<S::Input, PropertyError> {
+    S::parse_with(input, params).map_err(|error| PropertyError::Parse {
+        seed,
+        input: input.to_string(),
+        error,
+    })
+}
+
+/// Runs `check` on `cases` generated inputs with the seeds `seed`, `seed + 1`, ..., stopping at
+/// the first failure. Panics in `check` are failures too.
+fn for_generated<S, F>(seed: u64, cases: u64, mut check: F) -> Result<(), PropertyError>
+where
+    S: Generate,
+    F: FnMut(u64, &str, &Params) -> Result<(), PropertyError>,
+{
+    let params = generated_params::<S>();
+    for seed in seed..seed + cases {
+        let input = S::generate(&mut Rng::new(seed));
+        match panic::catch_unwind(AssertUnwindSafe(|| check(seed, &input, &params))) {
+            Ok(result) => result?,
+            Err(payload) => {
+                return Err(PropertyError::Panic {
+                    seed,
+                    input,
+                    message: panic_message(payload),
+                })
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the solution parses and solves generated inputs without panicking.
+pub fn check<S: Generate>(seed: u64, cases: u64) -> Result<(), PropertyError> {
+    for_generated::<S, _>(seed, cases, |seed, input, params| {
+        let parsed = parse_generated::<S>(seed, input, params)?;
+        S::part_one(&parsed);
+        S::part_two(&parsed);
+        Ok(())
+    })
+}
+
+/// Checks that both parts of the solution agree with the [`Reference`] on generated inputs.
+pub fn check_reference<S: Generate + Reference>(
+    seed: u64,
+    cases: u64,
+) -> Result<(), PropertyError> {
+    for_generated::<S, _>(seed, cases, |seed, input, params| {
+        let parsed = parse_generated::<S>(seed, input, params)?;
+        let parts = [
+            (1, S::reference_one(input, params), S::part_one(&parsed)),
+            (2, S::reference_two(input, params), S::part_two(&parsed)),
+        ];
+        for (part, expected, actual) in parts {
+            if expected != actual {
+                return Err(PropertyError::Mismatch {
+                    seed,
+                    part,
+                    input: input.to_string(),
+                    expected: describe(&expected),
+                    actual: describe(&actual),
+                });
+            }
+        }
+        Ok(())
+    })
+}
+
+/// The body of a fuzz target: parses arbitrary data as an input and solves it if it parses. Any
+/// panic is a crash for the fuzzer, so this finds the `unwrap()`s that malformed input can reach.
+pub fn fuzz<S: Solution>(data: &[u8]) {
+    if let Some(parsed) = std::str::from_utf8(data)
+        .ok()
+        .and_then(|input| S::parse(input).ok())
+    {
+        S::part_one(&parsed);
+        S::part_two(&parsed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::parse;
+
+    /// Sums the numbers of the input, with an off-by-one in part two for inputs with a 7.
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            parse::parse_lines(input, |line| parse::value(line, line))
+        }
+
+        fn part_one(input: &Self::Input) -> Option<Answer> {
+            Some(input.iter().sum::<i64>().into())
+        }
+
+        fn part_two(input: &Self::Input) -> Option<Answer> {
+            let bonus = input.contains(&7) as i64;
+            Some((input.iter().sum::<i64>() + bonus).into())
+        }
+    }
+
+    impl Generate for Sum {
+        fn generate(rng: &mut Rng) -> String {
+            let count = rng.below(5);
+            (0..count)
+                .map(|_| format!("{}\n", rng.range(0..=9)))
+                .collect()
+        }
+    }
+
+    impl Reference for Sum {
+        fn reference_one(input: &str, _params: &Params) -> Option<Answer> {
+            Some(
+                input
+                    .lines()
+                    .map(|l| l.parse::<i64>().unwrap())
+                    .sum::<i64>()
+                    .into(),
+            )
+        }
+
+        fn reference_two(input: &str, params: &Params) -> Option<Answer> {
+            Sum::reference_one(input, params)
+        }
+    }
+
+    #[test]
+    fn test_rng() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        assert_eq!(a.next_u64(), b.next_u64());
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&a.range(-3..=3)));
+            assert!(a.below(4) < 4);
+        }
+        assert_eq!(Rng::new(1).range(5..=5), 5);
+    }
+
+    #[test]
+    fn test_check_reference() {
+        assert!(check::<Sum>(0, 50).is_ok());
+        match check_reference::<Sum>(0, 200) {
+            Err(PropertyError::Mismatch { part, input, .. }) => {
+                assert_eq!(part, 2);
+                assert!(input.lines().any(|line| line == "7"));
+            }
+            other => panic!("expected a mismatch, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use regex::{Captures, Regex};
+
+use crate::lazy_regex;
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// The puzzle descriptions on a puzzle page, one for each unlocked part.
+fn articles(html: &str) -> Vec<&str> {
+    lazy_regex!(r#"(?s)<article class="day-desc">(.*?)</article>"#)
+        .captures_iter(html)
+        .map(|captures| captures.get(1).unwrap().as_str())
+        .collect()
+}
+
+type Replacement = fn(&Captures) -> String;
+
+/// Converts inline HTML, i.e. everything outside of `<pre>` blocks, to markdown. Relative links
+/// are resolved against `base_url`.
+fn inline_to_markdown(html: &str, base_url: &str) -> String {
+    let rules: [(&Regex, Replacement); 9] = [
+        (lazy_regex!(r"(?s)<h2[^>]*>(.*?)</h2>"), |c| {
+            format!("## {}\n\n", &c[1])
+        }),
+        (lazy_regex!(r"(?s)<code><em>(.*?)</em></code>"), |c| {
+            format!("**`{}`**", &c[1])
+        }),
+        (lazy_regex!(r"(?s)<em><code>(.*?)</code></em>"), |c| {
+            format!("**`{}`**", &c[1])
+        }),
+        (lazy_regex!(r"</?em[^>]*>"), |_| "**".to_string()),
+        (lazy_regex!(r"</?code>"), |_| "`".to_string()),
+        (lazy_regex!(r"<li>"), |_| "- ".to_string()),
+        (lazy_regex!(r"</li>"), |_| "\n".to_string()),
+        (lazy_regex!(r"</(p|ul)>"), |_| "\n\n".to_string()),
+        (lazy_regex!(r"<[^>]*>"), |_| String::new()),
+    ];
+
+    let links = lazy_regex!(r#"(?s)<a href="([^"]*)"[^>]*>(.*?)</a>"#);
+    let mut markdown = links
+        .replace_all(html, |c: &Captures| {
+            let href = &c[1];
+            if href.starts_with('/') {
+                format!("[{}]({}{})", &c[2], base_url, href)
+            } else {
+                format!("[{}]({})", &c[2], href)
+            }
+        })
+        .into_owned();
+    for (regex, replacement) in rules {
+        markdown = regex.replace_all(&markdown, replacement).into_owned();
+    }
+    decode_entities(&markdown)
+}
+
+/// Converts the puzzle descriptions on a puzzle page to markdown, with relative links pointing to
+/// `base_url`, the website the page was fetched from.
+pub fn to_markdown(html: &str, base_url: &str) -> String {
+    let pre = lazy_regex!(r"(?s)<pre><code>(.*?)</code></pre>");
+    let tags = lazy_regex!(r"<[^>]*>");
+    let mut markdown = String::new();
+
+    for article in articles(html) {
+        let mut last = 0;
+        for captures in pre.captures_iter(article) {
+            let block = captures.get(0).unwrap();
+            markdown.push_str(&inline_to_markdown(&article[last..block.start()], base_url));
+            let code = decode_entities(&tags.replace_all(&captures[1], ""));
+            markdown.push_str(&format!("```\n{}\n```\n\n", code.trim_end()));
+            last = block.end();
+        }
+        markdown.push_str(&inline_to_markdown(&article[last..], base_url));
+    }
+
+    let mut markdown = lazy_regex!(r"\n{3,}")
+        .replace_all(markdown.trim(), "\n\n")
+        .into_owned();
+    markdown.push('\n');
+    markdown
+}
+
+/// The first `<pre><code>` block of the puzzle descriptions, usually the example input.
+pub fn first_code_block(html: &str) -> Option<String> {
+    let pre = lazy_regex!(r"(?s)<pre><code>(.*?)</code></pre>");
+    let code = articles(html)
+        .into_iter()
+        .find_map(|article| pre.captures(article))?;
+    Some(decode_entities(
+        &lazy_regex!(r"<[^>]*>").replace_all(&code[1], ""),
+    ))
+}
+
+/// The answers the website accepted so far, shown below each solved part of the puzzle page.
+pub fn accepted_answers(html: &str) -> Vec<String> {
+    lazy_regex!(r"Your puzzle answer was <code>(.*?)</code>")
+        .captures_iter(html)
+        .map(|captures| decode_entities(&captures[1]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::DEFAULT_BASE_URL;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns writing down the number of <a href="https://en.wikipedia.org/wiki/Calorie">Calories</a> contained by the various meals.</p>
+<p>For example:</p>
+<pre><code>1000
+2000
+
+<em>4000</em>
+</code></pre>
+<ul>
+<li>The first Elf is carrying food with <code>1000</code> and <code>2000</code> Calories, a total of <code><em>3000</em></code> Calories.</li>
+</ul>
+<p>Find the Elf carrying the <em>most Calories</em>. See <a href="/2022/about">about</a> &amp; more.</p>
+</article>
+<p>Your puzzle answer was <code>24000</code>.</p>
+</main>"#;
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(PAGE, DEFAULT_BASE_URL),
+            "## --- Day 1: Calorie Counting ---
+
+The Elves take turns writing down the number of [Calories](https://en.wikipedia.org/wiki/Calorie) contained by the various meals.
+
+For example:
+
+```
+1000
+2000
+
+4000
+```
+
+- The first Elf is carrying food with `1000` and `2000` Calories, a total of **`3000`** Calories.
+
+Find the Elf carrying the **most Calories**. See [about](https://adventofcode.com/2022/about) & more.
+"
+        );
+    }
+
+    #[test]
+    fn test_relative_links_use_base_url() {
+        let markdown = to_markdown(PAGE, "http://localhost:8080");
+        assert!(markdown.contains("[about](http://localhost:8080/2022/about)"));
+        assert!(markdown.contains("(https://en.wikipedia.org/wiki/Calorie)"));
+    }
+
+    #[test]
+    fn test_first_code_block() {
+        assert_eq!(
+            first_code_block(PAGE),
+            Some("1000\n2000\n\n4000\n".to_string())
+        );
+        assert_eq!(first_code_block("<main></main>"), None);
+    }
+
+    #[test]
+    fn test_accepted_answers() {
+        assert_eq!(accepted_answers(PAGE), vec!["24000".to_string()]);
+        assert!(accepted_answers("<main></main>").is_empty());
+    }
+}
@@ -0,0 +1,211 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::answers::DayAnswers;
+
+pub const README_PATH: &str = "README.md";
+
+/// The stars table is shared with the GitHub action that tracks progress from the leaderboard.
+pub const STARS_MARKER: &str = "<!--- advent_readme_stars table --->";
+pub const BENCHMARKS_MARKER: &str = "<!--- benchmarking table --->";
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ReadmeError {
+    #[error("could not find two `{0}` lines, add them where the table should go")]
+    MissingMarker(String),
+}
+
+/// The byte range of the lines between the two `marker` lines of `readme`.
+fn section_range(readme: &str, marker: &str) -> Result<(usize, usize), ReadmeError> {
+    let missing = || ReadmeError::MissingMarker(marker.to_string());
+    let start = readme.find(marker).ok_or_else(missing)? + marker.len();
+    let end = start + readme[start..].find(marker).ok_or_else(missing)?;
+    Ok((start, end))
+}
+
+/// The lines between the two `marker` lines of `readme`.
+pub fn section<'a>(readme: &'a str, marker: &str) -> Result<&'a str, ReadmeError> {
+    let (start, end) = section_range(readme, marker)?;
+    Ok(readme[start..end].trim_start_matches('\n'))
+}
+
+/// Replaces the lines between the two `marker` lines of `readme` with `content`.
+pub fn replace_section(readme: &str, marker: &str, content: &str) -> Result<String, ReadmeError> {
+    let (start, end) = section_range(readme, marker)?;
+    Ok(format!(
+        "{}\n{}{}",
+        &readme[..start],
+        content,
+        &readme[end..]
+    ))
+}
+
+/// The part cells of every day row in a stars table, e.g. `["⭐", " "]`.
+fn star_cells(table: &str) -> BTreeMap<u8, [String; 2]> {
+    table
+        .lines()
+        .filter_map(|line| {
+            let day = line
+                .strip_prefix("| [Day ")?
+                .split(']')
+                .next()?
+                .parse()
+                .ok()?;
+            let cells: Vec<&str> = line.split('|').map(str::trim).collect();
+            let cell = |index: usize| match cells.get(index) {
+                Some(cell) if !cell.is_empty() => cell.to_string(),
+                _ => " ".to_string(),
+            };
+            Some((day, [cell(2), cell(3)]))
+        })
+        .collect()
+}
+
+/// The stars table for days 1 to `last_day`. Parts with an accepted answer get a star, all other
+/// parts keep their cell from `current`, the table in the readme now. Stars that the GitHub
+/// action added for answers that were never recorded are not lost.
+pub fn stars_table(
+    year: u16,
+    answers: &BTreeMap<u8, DayAnswers>,
+    last_day: u8,
+    current: &str,
+) -> String {
+    let current = star_cells(current);
+    let mut table = format!(
+        "## {} Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n",
+        year
+    );
+    let last_day = current.keys().copied().fold(last_day, u8::max);
+    for day in 1..=last_day {
+        let star = |part: u8| {
+            if answers.get(&day).and_then(|a| a.part(part)).is_some() {
+                "⭐".to_string()
+            } else {
+                current
+                    .get(&day)
+                    .map_or(" ".to_string(), |cells| cells[part as usize - 1].clone())
+            }
+        };
+        table.push_str(&format!(
+            "| [Day {}](https://adventofcode.com/{}/day/{}) | {} | {} |\n",
+            day,
+            year,
+            day,
+            star(1),
+            star(2)
+        ));
+    }
+    table
+}
+
+/// The median timings of one day, `total` includes parsing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayTimings {
+    pub day: u8,
+    pub part_one: Option<Duration>,
+    pub part_two: Option<Duration>,
+    pub total: Duration,
+}
+
+pub fn benchmarks_table(year: u16, timings: &[DayTimings]) -> String {
+    let format =
+        |timing: Option<Duration>| timing.map_or("-".to_string(), |t| format!("`{:.2?}`", t));
+    let mut table = format!(
+        "## {} Benchmarks\n\n| Day | Part 1 | Part 2 | Total |\n| :---: | :---: | :---: | :---: |\n",
+        year
+    );
+    for timings in timings {
+        table.push_str(&format!(
+            "| [Day {}](./src/solutions/y{}/day{:02}.rs) | {} | {} | {} |\n",
+            timings.day,
+            year,
+            timings.day,
+            format(timings.part_one),
+            format(timings.part_two),
+            format(Some(timings.total))
+        ));
+    }
+    let total: Duration = timings.iter().map(|t| t.total).sum();
+    table.push_str(&format!(
+        "\n**Total: {:.2?}** _(median timings, including parsing)_\n",
+        total
+    ));
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    #[test]
+    fn test_replace_section() {
+        let readme = "# AoC\n<!--- table --->\nold\n<!--- table --->\nrest\n";
+        assert_eq!(
+            replace_section(readme, "<!--- table --->", "new\n"),
+            Ok("# AoC\n<!--- table --->\nnew\n<!--- table --->\nrest\n".to_string())
+        );
+        assert_eq!(
+            replace_section("# AoC\n<!--- table --->\n", "<!--- table --->", "new\n"),
+            Err(ReadmeError::MissingMarker("<!--- table --->".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_stars_table() {
+        let answers = BTreeMap::from([(
+            1,
+            DayAnswers {
+                part_one: Some(Answer::Number(24000)),
+                part_two: None,
+            },
+        )]);
+        assert_eq!(
+            stars_table(2022, &answers, 2, ""),
+            "## 2022 Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n\
+             | [Day 1](https://adventofcode.com/2022/day/1) | ⭐ |   |\n\
+             | [Day 2](https://adventofcode.com/2022/day/2) |   |   |\n"
+        );
+    }
+
+    #[test]
+    fn test_stars_table_keeps_unrecorded_stars() {
+        let readme = "<!--- stars --->\n## 2022 Results\n\n| Day | Part 1 | Part 2 |\n\
+             | :---: | :---: | :---: |\n\
+             | [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |\n\
+             | [Day 2](https://adventofcode.com/2022/day/2) | ⭐ |   |\n\
+             <!--- stars --->\n";
+        let current = section(readme, "<!--- stars --->").unwrap();
+        assert_eq!(stars_table(2022, &BTreeMap::new(), 1, current), current);
+
+        let answers = BTreeMap::from([(
+            2,
+            DayAnswers {
+                part_one: None,
+                part_two: Some(Answer::Number(9)),
+            },
+        )]);
+        assert!(stars_table(2022, &answers, 2, current)
+            .ends_with("| [Day 2](https://adventofcode.com/2022/day/2) | ⭐ | ⭐ |\n"));
+    }
+
+    #[test]
+    fn test_benchmarks_table() {
+        let timings = [DayTimings {
+            day: 5,
+            part_one: Some(Duration::from_micros(30)),
+            part_two: None,
+            total: Duration::from_micros(50),
+        }];
+        let table = benchmarks_table(2022, &timings);
+        assert!(table
+            .contains("| [Day 5](./src/solutions/y2022/day05.rs) | `30.00µs` | - | `50.00µs` |\n"));
+        assert!(table.ends_with("**Total: 50.00µs** _(median timings, including parsing)_\n"));
+    }
+}
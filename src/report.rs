@@ -0,0 +1,146 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::cli::Parts;
+use crate::Answer;
+
+/// Output format of the all-days runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format \"{}\", expected text or json", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    Unsolved,
+    /// The day is solved, but its puzzle input has not been downloaded.
+    MissingInput,
+    /// The day is solved, but its puzzle input could not be parsed.
+    InvalidInput,
+}
+
+/// A machine-readable result for one part of one day.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub elapsed_ns: Option<u64>,
+    pub status: Status,
+}
+
+impl Record {
+    pub fn new(
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: Option<Answer>,
+        elapsed: Option<Duration>,
+    ) -> Self {
+        let status = if answer.is_some() {
+            Status::Solved
+        } else {
+            Status::Unsolved
+        };
+        Record {
+            year,
+            day,
+            part,
+            answer,
+            elapsed_ns: elapsed.map(|e| e.as_nanos() as u64),
+            status,
+        }
+    }
+
+    /// Records for the selected parts of a day without a registered solution.
+    pub fn unsolved(year: u16, day: u8, parts: Parts) -> Vec<Record> {
+        (1..=2)
+            .filter(|part| parts.includes(*part))
+            .map(|part| Record::new(year, day, part, None, None))
+            .collect()
+    }
+
+    /// Records for the selected parts of a day that could not be run because its input is missing.
+    pub fn missing_input(year: u16, day: u8, parts: Parts) -> Vec<Record> {
+        Record::failed(year, day, parts, Status::MissingInput)
+    }
+
+    /// Records for the selected parts of a day whose input could not be parsed.
+    pub fn invalid_input(year: u16, day: u8, parts: Parts) -> Vec<Record> {
+        Record::failed(year, day, parts, Status::InvalidInput)
+    }
+
+    fn failed(year: u16, day: u8, parts: Parts, status: Status) -> Vec<Record> {
+        Record::unsolved(year, day, parts)
+            .into_iter()
+            .map(|record| Record { status, ..record })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_json() {
+        let record = Record::new(
+            2022,
+            1,
+            2,
+            Some(45000.into()),
+            Some(Duration::from_micros(3)),
+        );
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"year":2022,"day":1,"part":2,"answer":45000,"elapsed_ns":3000,"status":"solved"}"#
+        );
+
+        let record = Record::new(2022, 10, 2, Some("#.\n.#".into()), None);
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r##"{"year":2022,"day":10,"part":2,"answer":"#.\n.#","elapsed_ns":null,"status":"solved"}"##
+        );
+
+        let records = Record::unsolved(2022, 25, Parts::Both);
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            serde_json::to_string(&records[0]).unwrap(),
+            r#"{"year":2022,"day":25,"part":1,"answer":null,"elapsed_ns":null,"status":"unsolved"}"#
+        );
+
+        let records = Record::missing_input(2022, 3, Parts::Two);
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            serde_json::to_string(&records[0]).unwrap(),
+            r#"{"year":2022,"day":3,"part":2,"answer":null,"elapsed_ns":null,"status":"missing_input"}"#
+        );
+
+        let records = Record::invalid_input(2022, 5, Parts::Both);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].status, Status::InvalidInput);
+    }
+}
@@ -0,0 +1,126 @@
+/*
+ * Solutions are registered here so that the all-days runner can call them in-process.
+ * Every event year has its own module, e.g. `y2022`, with a `solutions!` list of its days.
+ * `cargo scaffold` adds new years to the `years!` list at the bottom of this file.
+ */
+use crate::bench::DayBench;
+use crate::cli::Parts;
+use crate::helpers::parse::ParseError;
+use crate::props::PropertyError;
+use crate::DayResult;
+
+/// Compares a solution with its reference on the generated inputs with seeds `seed..seed + cases`.
+pub type CrossCheck = fn(seed: u64, cases: u64) -> Result<(), PropertyError>;
+
+/// A registered solution for a single day.
+pub struct Day {
+    pub day: u8,
+    pub run: fn(&str, Parts) -> Result<DayResult, ParseError>,
+    pub bench: fn(&str, Parts) -> Result<DayBench, ParseError>,
+    /// Set for solutions that are registered `with generate` or `with reference`, see
+    /// [`crate::props::check`].
+    pub check: Option<CrossCheck>,
+    /// Set for solutions that are registered `with reference`, see [`crate::props::Reference`].
+    pub cross_check: Option<CrossCheck>,
+}
+
+/// The registered solutions of one event year.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+/// Looks up the registered solutions for `year`.
+pub fn days(year: u16) -> &'static [Day] {
+    YEARS
+        .iter()
+        .find(|y| y.year == year)
+        .map_or(&[], |y| y.days)
+}
+
+/// Looks up the registered solution for `day` of `year`.
+pub fn get(year: u16, day: u8) -> Option<&'static Day> {
+    days(year).iter().find(|d| d.day == day)
+}
+
+macro_rules! solutions {
+    ($($day:literal => $module:ident::$solution:ident $(with $props:ident)?),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub const DAYS: &[$crate::solutions::Day] = &[$(
+            $crate::solutions::Day {
+                day: $day,
+                run: $crate::run::<$module::$solution>,
+                bench: $crate::bench::run::<$module::$solution>,
+                check: solutions!(@check $module::$solution $($props)?),
+                cross_check: solutions!(@cross_check $module::$solution $($props)?),
+            },
+        )*];
+    };
+    (@check $module:ident::$solution:ident) => {
+        None
+    };
+    (@check $module:ident::$solution:ident generate) => {
+        Some($crate::props::check::<$module::$solution>)
+    };
+    (@check $module:ident::$solution:ident reference) => {
+        Some($crate::props::check::<$module::$solution>)
+    };
+    (@cross_check $module:ident::$solution:ident) => {
+        None
+    };
+    (@cross_check $module:ident::$solution:ident generate) => {
+        None
+    };
+    (@cross_check $module:ident::$solution:ident reference) => {
+        Some($crate::props::check_reference::<$module::$solution>)
+    };
+}
+
+macro_rules! years {
+    ($($year:literal => $module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub const YEARS: &[Year] = &[$(
+            Year {
+                year: $year,
+                days: $module::DAYS,
+            },
+        )*];
+    };
+}
+
+years! {
+    2022 => y2022,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::YEARS;
+
+    #[test]
+    fn test_generated() {
+        for year in YEARS {
+            for day in year.days {
+                if let Some(check) = day.check {
+                    if let Err(e) = check(0, 100) {
+                        panic!("{} Day {:02}: {}", year.year, day.day, e);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cross_check() {
+        for year in YEARS {
+            for day in year.days {
+                if let Some(cross_check) = day.cross_check {
+                    if let Err(e) = cross_check(0, 30) {
+                        panic!("{} Day {:02}: {}", year.year, day.day, e);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::helpers::parse::{self, ParseError};
+use crate::{Answer, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_paragraphs(input, |elf| {
+            let calories = parse::parse_lines(elf, |line| parse::value::<u32>(line, line))?;
+            Ok(calories.into_iter().sum())
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let mut elves_totals = input.clone();
+        elves_totals.sort();
+        elves_totals.last().copied().map(Answer::from)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let mut elves_totals = input.clone();
+        elves_totals.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        Some(elves_totals.iter().take(3).sum::<u32>().into())
+    }
+}
@@ -1,5 +1,8 @@
 use anyhow::{anyhow, Result};
 
+use crate::helpers::parse::{self, ParseError};
+use crate::{Answer, Solution};
+
 #[derive(Debug, Clone)]
 enum Roshambo {
     Rock,
@@ -80,75 +83,62 @@ impl GameResult {
     }
 }
 
-fn parse_input_one(input: &str) -> Result<Vec<Vec<Roshambo>>> {
-    input
-        .lines()
-        .map(|line| line.split(' ').map(Roshambo::from_str).collect())
+fn strategy_one(rounds: &[(String, String)]) -> Result<Vec<Vec<Roshambo>>> {
+    rounds
+        .iter()
+        .map(|(opponent, own)| Ok(vec![Roshambo::from_str(opponent)?, Roshambo::from_str(own)?]))
         .collect()
 }
 
-pub fn part_one(input: &str) -> Option<i32> {
-    parse_input_one(input)
-        .map(|turns| {
-            turns
-                .iter()
-                .map(|turn| {
-                    let result = GameResult::from_round(&turn[1], &turn[0]);
-                    result.point_value() + turn[1].point_value()
-                })
-                .sum()
-        })
-        .ok()
-}
-
-fn parse_input_two(input: &str) -> Result<Vec<Vec<Roshambo>>> {
-    Ok(input
-        .lines()
-        .map(|line| {
-            let mut split = line.split(' ');
-            let opponent_choice = Roshambo::from_str(split.next().unwrap()).unwrap();
-            let desired_result = GameResult::from_str(split.next().unwrap()).unwrap();
-            vec![
+fn strategy_two(rounds: &[(String, String)]) -> Result<Vec<Vec<Roshambo>>> {
+    rounds
+        .iter()
+        .map(|(opponent, desired)| {
+            let opponent_choice = Roshambo::from_str(opponent)?;
+            let desired_result = GameResult::from_str(desired)?;
+            Ok(vec![
                 opponent_choice.clone(),
                 Roshambo::from_result(&opponent_choice, &desired_result),
-            ]
+            ])
         })
-        .collect())
+        .collect()
 }
 
-pub fn part_two(input: &str) -> Option<i32> {
-    parse_input_two(input)
-        .map(|turns| {
-            turns
-                .iter()
-                .map(|turn| {
-                    let result = GameResult::from_round(&turn[1], &turn[0]);
-                    result.point_value() + turn[1].point_value()
-                })
-                .sum()
+fn total_score(turns: &[Vec<Roshambo>]) -> i32 {
+    turns
+        .iter()
+        .map(|turn| {
+            let result = GameResult::from_round(&turn[1], &turn[0]);
+            result.point_value() + turn[1].point_value()
         })
-        .ok()
+        .sum()
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(String, String)>;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, |line| match line.trim().split_once(' ') {
+            Some((a, b)) => Ok((a.to_string(), b.to_string())),
+            None => Err(ParseError::at(
+                line,
+                line.trim_start(),
+                "expected two letters like `A Y`",
+            )),
+        })
+    }
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_one(&input), Some(15));
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        strategy_one(input)
+            .map(|turns| total_score(&turns).into())
+            .ok()
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_two(&input), Some(12));
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        strategy_two(input)
+            .map(|turns| total_score(&turns).into())
+            .ok()
     }
 }
@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::helpers::parse::{self, ParseError};
+use crate::{Answer, Solution};
+
+#[derive(Debug)]
+struct Compartment(Vec<char>);
+#[derive(Debug)]
+pub struct Rucksack(Vec<Compartment>);
+
+#[derive(Debug)]
+struct Priority(usize);
+impl From<&char> for Priority {
+    fn from(ch: &char) -> Priority {
+        let lowercase = 'a'..='z';
+        let uppercase = 'A'..='Z';
+        Priority(
+            lowercase
+                .chain(uppercase)
+                .find_position(|c| c == ch)
+                .map(|(i, _)| i)
+                .unwrap_or(0)
+                + 1,
+        )
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Rucksack>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, |line| {
+            let items = line.trim();
+            if let Some(item) = items.matches(|c: char| !c.is_ascii_alphabetic()).next() {
+                let message = format!("expected an item letter, found '{}'", item);
+                return Err(ParseError::at(line, item, message));
+            }
+            if !items.len().is_multiple_of(2) {
+                let message = "expected two compartments with the same number of items";
+                return Err(ParseError::at(line, items, message));
+            }
+            let (first, second) = items.split_at(items.len() / 2);
+            Ok(Rucksack(vec![
+                Compartment(first.chars().collect()),
+                Compartment(second.chars().collect()),
+            ]))
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let mut priority_sum = 0;
+        for Rucksack(compartments) in input {
+            let char_sets: Vec<HashSet<char>> = compartments
+                .iter()
+                .map(|Compartment(chars)| HashSet::from_iter(chars.iter().cloned()))
+                .collect();
+            let first = char_sets.first().unwrap();
+            let second = char_sets.last().unwrap();
+            let intersection = first.intersection(second);
+            for common in intersection {
+                let Priority(val) = common.into();
+                priority_sum += val;
+            }
+        }
+        Some(priority_sum.into())
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let mut priority_sum = 0;
+        for elf_group in input.chunks(3) {
+            let mut char_sets = Vec::new();
+            for Rucksack(compartments) in elf_group {
+                let char_set: HashSet<char> = HashSet::from_iter(
+                    compartments
+                        .iter()
+                        .flat_map(|Compartment(chars)| chars.iter().cloned()),
+                );
+                char_sets.push(char_set);
+            }
+            let intersection = char_sets
+                .iter()
+                .cloned()
+                .reduce(|a, b| a.intersection(&b).copied().collect())
+                .unwrap();
+            for badge in intersection {
+                let Priority(val) = (&badge).into();
+                priority_sum += val;
+            }
+        }
+        Some(priority_sum.into())
+    }
+}
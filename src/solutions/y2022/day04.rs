@@ -0,0 +1,51 @@
+use crate::helpers::parse::{self, ParseError};
+use crate::{Answer, Solution};
+
+/// Parses the range `part` of `line`, e.g. `2-4`.
+fn parse_range(line: &str, part: &str) -> Result<(u32, u32), ParseError> {
+    let (start, end) = part
+        .split_once('-')
+        .ok_or_else(|| ParseError::at(line, part, "expected a range like `2-4`"))?;
+    Ok((parse::value(line, start)?, parse::value(line, end)?))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<((u32, u32), (u32, u32))>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, |line| {
+            let (first, second) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::at(line, line, "expected two ranges like `2-4,6-8`"))?;
+            Ok((parse_range(line, first)?, parse_range(line, second)?))
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let count = input
+            .iter()
+            .filter(|(first_elf, second_elf)| {
+                let (first_start, first_end) = first_elf;
+                let (second_start, second_end) = second_elf;
+                (first_start <= second_start && first_end >= second_end)
+                    || (second_start <= first_start && second_end >= first_end)
+            })
+            .count();
+        Some(count.into())
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let count = input
+            .iter()
+            .filter(|(first_elf, second_elf)| {
+                let (first_start, first_end) = first_elf;
+                let (second_start, second_end) = second_elf;
+                (first_end >= second_start && first_start <= second_start)
+                    || (second_end >= first_start && second_start <= first_start)
+            })
+            .count();
+        Some(count.into())
+    }
+}
@@ -0,0 +1,95 @@
+use itertools::Itertools;
+
+use crate::helpers::parse::{self, ParseError};
+use crate::{Answer, Solution};
+
+#[derive(Debug)]
+pub struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+fn apply_moves_one(stacks: &mut [Vec<char>], moves: &[Move]) {
+    for Move { count, from, to } in moves {
+        for _ in 0..*count {
+            let val = {
+                let from_stack = stacks.get_mut(from - 1).unwrap();
+                from_stack.pop().unwrap()
+            };
+            let to_stack = stacks.get_mut(to - 1).unwrap();
+            to_stack.push(val);
+        }
+    }
+}
+
+fn apply_moves_two(stacks: &mut [Vec<char>], moves: &[Move]) {
+    for Move { count, from, to } in moves {
+        let mut moving = {
+            let from_stack = stacks.get_mut(from - 1).unwrap();
+            let split_point = from_stack.len() - count;
+            from_stack.drain(split_point..).collect_vec()
+        };
+        let to_stack = stacks.get_mut(to - 1).unwrap();
+        to_stack.append(&mut moving);
+    }
+}
+
+fn parse_stacks_and_moves(input: &str) -> Result<(Vec<Vec<char>>, Vec<Move>), ParseError> {
+    let mut paragraphs = parse::paragraphs(input);
+    let (Some((_, stacks_str)), Some((moves_line, moves_str))) =
+        (paragraphs.next(), paragraphs.next())
+    else {
+        return Err(ParseError::new(1, 1, "expected the stacks and the moves"));
+    };
+
+    let mut stacks = Vec::new();
+    for line in stacks_str.lines().rev().skip(1) {
+        for (stack_idx, char_chunk) in line.chars().chunks(4).into_iter().enumerate() {
+            if stacks.len() <= stack_idx {
+                stacks.push(Vec::new());
+            }
+            let mut char_chunk = char_chunk;
+            let stack = stacks.get_mut(stack_idx);
+            match (char_chunk.nth(1), stack) {
+                (Some(ch), Some(stack)) if ch != ' ' => stack.push(ch),
+                _ => (),
+            }
+        }
+    }
+
+    let moves = parse::parse_lines(moves_str, |line| {
+        let [count, from, to] = parse::ints_array(line)?;
+        if from == 0 || from > stacks.len() || to == 0 || to > stacks.len() {
+            return Err(ParseError::at(line, line, "unknown stack"));
+        }
+        Ok(Move { count, from, to })
+    })
+    .map_err(|e| e.below(moves_line - 1))?;
+
+    Ok((stacks, moves))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<Vec<char>>, Vec<Move>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_stacks_and_moves(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let (stacks, moves) = input;
+        let mut stacks = stacks.clone();
+        apply_moves_one(&mut stacks, moves);
+        Some(stacks.iter().filter_map(|s| s.last()).join("").into())
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let (stacks, moves) = input;
+        let mut stacks = stacks.clone();
+        apply_moves_two(&mut stacks, moves);
+        Some(stacks.iter().filter_map(|s| s.last()).join("").into())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::helpers::parse::ParseError;
+use crate::{Answer, Solution};
+
+fn find_marker_position(chars: &[char], marker_length: usize) -> Option<usize> {
+    chars
+        .windows(marker_length)
+        .enumerate()
+        .find_map(|(idx, chars)| {
+            let mut all_chars = chars.to_vec();
+            all_chars.sort();
+            all_chars.dedup();
+            if all_chars.len() == marker_length {
+                Some(idx)
+            } else {
+                None
+            }
+        })
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.chars().collect())
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let marker_size = 4;
+        find_marker_position(input, marker_size).map(|idx| (idx + marker_size).into())
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let marker_size = 14;
+        find_marker_position(input, marker_size).map(|idx| (idx + marker_size).into())
+    }
+}
@@ -0,0 +1,124 @@
+use indextree::{Arena, NodeId};
+
+use crate::helpers::parse::{self, ParseError};
+use crate::params::{Param, ParamError, Params};
+use crate::{Answer, Solution};
+
+#[derive(Clone)]
+pub struct Node {
+    name: String,
+    size: u32,
+}
+
+pub struct FileSystem {
+    arena: Arena<Node>,
+    disk_size: u64,
+    update_size: u64,
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = FileSystem;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "disk_size",
+            default: "70000000",
+            description: "the total disk space",
+        },
+        Param {
+            name: "update_size",
+            default: "30000000",
+            description: "the unused space the update needs",
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn check_params(params: &Params) -> Result<(), ParamError> {
+        let disk_size: u64 = params.try_get("disk_size")?;
+        let update_size: u64 = params.try_get("update_size")?;
+        if update_size > disk_size {
+            let reason = format!("the update does not fit on a disk of {}", disk_size);
+            return Err(ParamError::invalid("update_size", params.get_str("update_size"), reason));
+        }
+        Ok(())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let mut arena = Arena::new();
+        let root = arena.new_node(Node {
+            name: "/".to_string(),
+            size: 0,
+        });
+        let mut current_id = root;
+
+        for (index, line) in input.lines().enumerate() {
+            let error = |part: &str, message: String| ParseError::at(line, part, message).below(index);
+            if let Some(command) = line.strip_prefix("$ ") {
+                current_id = match command.split_once(' ') {
+                    None if command.trim() == "ls" => current_id,
+                    Some(("cd", "/")) => root,
+                    Some(("cd", "..")) => arena[current_id]
+                        .parent()
+                        .ok_or_else(|| error(command, "`cd ..` in the root directory".to_string()))?,
+                    Some(("cd", dir)) => current_id
+                        .children(&arena)
+                        .find(|id| arena[*id].get().name == dir)
+                        .ok_or_else(|| error(dir, format!("unknown directory \"{}\"", dir)))?,
+                    _ => return Err(error(command, "expected `cd <dir>` or `ls`".to_string())),
+                };
+            } else if let Some((size, name)) = line.split_once(' ') {
+                if size == "dir" {
+                    let id = arena.new_node(Node {
+                        name: name.to_string(),
+                        size: 0,
+                    });
+                    current_id.append(id, &mut arena);
+                } else {
+                    let size: u32 = parse::value(line, size).map_err(|e| e.below(index))?;
+                    current_id
+                        .ancestors(&arena)
+                        .collect::<Vec<NodeId>>()
+                        .into_iter()
+                        .for_each(|id| arena[id].get_mut().size += size);
+                }
+            } else if !line.trim().is_empty() {
+                let message = "expected a command, `dir <name>` or `<size> <name>`";
+                return Err(error(line, message.to_string()));
+            }
+        }
+
+        Ok(FileSystem {
+            arena,
+            disk_size: params.try_get("disk_size")?,
+            update_size: params.try_get("update_size")?,
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        Some(
+            input
+                .arena
+                .iter()
+                .map(|entry| entry.get().size)
+                .filter(|size| *size < 100000)
+                .sum::<u32>()
+                .into(),
+        )
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let mut values = input.arena.iter().map(|entry| entry.get().size);
+        let total_size = u64::from(values.next()?);
+        // nothing needs to be deleted if there already is enough free space
+        let needed = (total_size + input.update_size).saturating_sub(input.disk_size);
+        values
+            .filter(|x| u64::from(*x) >= needed)
+            .min()
+            .map(Answer::from)
+    }
+}
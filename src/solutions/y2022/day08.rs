@@ -0,0 +1,56 @@
+use crate::helpers::parse::ParseError;
+use crate::helpers::grid::{Grid, OFFSETS_4};
+use crate::{Answer, Solution};
+
+type TreeGrid = Grid<u32>;
+
+fn count_visible(grid: &TreeGrid) -> usize {
+    grid.iter()
+        .filter(|(pos, height)| {
+            OFFSETS_4
+                .into_iter()
+                .any(|step| grid.ray(*pos, step).all(|tree| grid[tree] < **height))
+        })
+        .count()
+}
+
+fn scenic_score(grid: &TreeGrid) -> Option<u32> {
+    grid.iter()
+        .map(|(pos, height)| {
+            OFFSETS_4
+                .into_iter()
+                .map(|step| {
+                    let mut seen = 0;
+                    for tree in grid.ray(pos, step) {
+                        seen += 1;
+                        if grid[tree] >= *height {
+                            break;
+                        }
+                    }
+                    seen
+                })
+                .product()
+        })
+        .max()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = TreeGrid;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::try_parse(input, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| format!("expected a tree height, found '{}'", c))
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        Some(count_visible(input).into())
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        scenic_score(input).map(Answer::from)
+    }
+}
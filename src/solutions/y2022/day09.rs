@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::helpers::geometry::{Dir4, Point2};
+use crate::helpers::parse::{self, ParseError};
+use crate::params::Params;
+use crate::props::{Generate, Reference, Rng};
+use crate::{Answer, Solution};
+
+fn follow_head(head: Point2, tail: Point2) -> Point2 {
+    if head.chebyshev(tail) > 1 {
+        // not touching, so the tail moves one step towards the head, diagonally if needed
+        tail + (head - tail).signum()
+    } else {
+        tail
+    }
+}
+
+fn generate_tail_set(directions: &[Dir4], knot_count: usize) -> HashSet<Point2> {
+    let mut coords = vec![Point2::ORIGIN; knot_count];
+    let mut tail_set = HashSet::new();
+    for dir in directions {
+        coords[0] += dir.delta();
+        for knot_idx in 1..knot_count {
+            coords[knot_idx] = follow_head(coords[knot_idx - 1], coords[knot_idx]);
+        }
+        tail_set.insert(coords[knot_count - 1]);
+    }
+    tail_set
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Dir4>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let moves = parse::parse_lines(input, |line| {
+            let (direction_str, distance_str) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at(line, line, "expected a direction and a distance"))?;
+            let direction = direction_str
+                .chars()
+                .exactly_one()
+                .ok()
+                .and_then(|c| Dir4::try_from(c).ok())
+                .ok_or_else(|| {
+                    let message = format!("unknown direction \"{}\"", direction_str);
+                    ParseError::at(line, direction_str, message)
+                })?;
+            let count: usize = parse::value(line, distance_str)?;
+            Ok((direction, count))
+        })?;
+        Ok(moves
+            .into_iter()
+            .flat_map(|(direction, count)| (0..count).map(move |_| direction))
+            .collect())
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let tail_set = generate_tail_set(input, 2);
+        Some(tail_set.len().into())
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let tail_set = generate_tail_set(input, 10);
+        Some(tail_set.len().into())
+    }
+}
+
+impl Generate for Day09 {
+    fn generate(rng: &mut Rng) -> String {
+        let motions = rng.range(1..=30);
+        (0..motions)
+            .map(|_| format!("{} {}\n", rng.choose(&['U', 'D', 'L', 'R']), rng.range(1..=9)))
+            .collect()
+    }
+}
+
+/// Moves every knot that is not touching the knot in front of it to the neighboring cell
+/// closest to that knot, one step of the head at a time.
+fn reference_tail_count(input: &str, knot_count: usize) -> usize {
+    let mut knots = vec![(0i64, 0i64); knot_count];
+    let mut visited = vec![(0, 0)];
+    for line in input.lines() {
+        let (direction, distance) = line.split_once(' ').unwrap();
+        let (dx, dy) = match direction {
+            "U" => (0, -1),
+            "D" => (0, 1),
+            "L" => (-1, 0),
+            _ => (1, 0),
+        };
+        for _ in 0..distance.parse().unwrap() {
+            knots[0] = (knots[0].0 + dx, knots[0].1 + dy);
+            for i in 1..knot_count {
+                let (hx, hy) = knots[i - 1];
+                let (tx, ty) = knots[i];
+                if (hx - tx).abs() <= 1 && (hy - ty).abs() <= 1 {
+                    continue;
+                }
+                knots[i] = (-1..=1)
+                    .flat_map(|x| (-1..=1).map(move |y| (tx + x, ty + y)))
+                    .min_by_key(|(x, y)| (hx - x).pow(2) + (hy - y).pow(2))
+                    .unwrap();
+            }
+            visited.push(knots[knot_count - 1]);
+        }
+    }
+    visited.sort();
+    visited.dedup();
+    visited.len()
+}
+
+impl Reference for Day09 {
+    fn reference_one(input: &str, _params: &Params) -> Option<Answer> {
+        Some(reference_tail_count(input, 2).into())
+    }
+
+    fn reference_two(input: &str, _params: &Params) -> Option<Answer> {
+        Some(reference_tail_count(input, 10).into())
+    }
+}
@@ -0,0 +1,85 @@
+use itertools::Itertools;
+
+use crate::helpers::parse::{self, ParseError};
+use crate::{Answer, Solution};
+
+#[derive(Debug)]
+pub enum Command {
+    Noop,
+    AddX(i32),
+}
+
+fn execute(cmds: &[Command]) -> Vec<i32> {
+    let mut x = 1;
+    let mut results = vec![x];
+    for cmd in cmds {
+        match cmd {
+            Command::Noop => {
+                results.push(x);
+            }
+            Command::AddX(v) => {
+                results.push(x);
+                x += v;
+                results.push(x);
+            }
+        }
+    }
+    results
+}
+
+fn draw(register: &[i32]) -> String {
+    (0..6)
+        .map(|y| {
+            (0..40)
+                .map(|x| {
+                    let tick = x + (y * 40);
+                    // once the program is done, X keeps its last value
+                    let x_val = register.get(tick).or(register.last()).copied().unwrap();
+                    if x_val.abs_diff(x as i32) < 2 {
+                        "#"
+                    } else {
+                        "."
+                    }
+                })
+                .collect_vec()
+                .join("")
+        })
+        .collect_vec()
+        .join("\n")
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, |line| match line.trim().split_once(' ') {
+            None if line.trim() == "noop" => Ok(Command::Noop),
+            Some(("addx", value)) => Ok(Command::AddX(parse::value(line, value)?)),
+            _ => Err(ParseError::at(
+                line,
+                line.trim_start(),
+                "expected `noop` or `addx <value>`",
+            )),
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        Some(
+            execute(input)
+                .iter()
+                .enumerate()
+                .skip(19)
+                .filter(|(i, _)| (i + 1) == 20 || (i + 1 - 20) % 40 == 0)
+                .map(|(i, v)| (i + 1) as i32 * v)
+                .sum::<i32>()
+                .into(),
+        )
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let register = execute(input);
+        Some(draw(&register).into())
+    }
+}
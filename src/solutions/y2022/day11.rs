@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::helpers::parse::{self, ParseError};
+use crate::params::{Param, ParamError, Params};
+use crate::{Answer, Solution};
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Add(u64),
+    Mul(u64),
+    Pow(u32),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Item {
+    worry: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<Item>,
+    operation: Operation,
+    decision_divisible_by: u64,
+    true_target: usize,
+    false_target: usize,
+    item_inspection_count: usize,
+}
+
+fn execute(monkeys: &mut HashMap<usize, Monkey>, rounds: usize, worry_decrease: bool) {
+    let cd: u64 = monkeys
+        .values()
+        .map(|m| m.decision_divisible_by)
+        .product();
+    for _ in 0..rounds {
+        for monkey_idx in 0..monkeys.len() {
+            let (true_target, false_target) = {
+                let monkey = monkeys.get(&monkey_idx).unwrap();
+                (monkey.true_target, monkey.false_target)
+            };
+            let [monkey, true_target, false_target] = monkeys
+                .get_disjoint_mut([&monkey_idx, &true_target, &false_target])
+                .map(Option::unwrap);
+            for item in monkey.items.iter_mut() {
+                use Operation::*;
+                match monkey.operation {
+                    Pow(x) => item.worry = item.worry.pow(x),
+                    Mul(x) => item.worry *= x,
+                    Add(x) => item.worry += x,
+                }
+                monkey.item_inspection_count += 1;
+                if worry_decrease {
+                    item.worry /= 3;
+                } else {
+                    item.worry %= cd;
+                }
+                if item.worry % monkey.decision_divisible_by == 0 {
+                    true_target.items.push(*item);
+                } else {
+                    false_target.items.push(*item);
+                }
+            }
+            monkey.items.clear();
+        }
+    }
+}
+
+fn monkey_business(monkeys: &HashMap<usize, Monkey>) -> Option<usize> {
+    monkeys
+        .values()
+        .map(|m| m.item_inspection_count)
+        .sorted()
+        .rev()
+        .take(2)
+        .reduce(|a, b| a * b)
+}
+
+/// The text after `prefix` in `value`, one of the fields in `paragraph`.
+fn strip<'a>(paragraph: &str, value: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    value
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(paragraph, value, format!("expected `{}...`", prefix)))
+}
+
+/// Parses monkey `index` of `count`, e.g. `Monkey 0:` followed by its items, operation and test.
+fn parse_monkey(paragraph: &str, index: usize, count: usize) -> Result<Monkey, ParseError> {
+    let fields = parse::key_values(paragraph, ":")?;
+    let field = |key: &str| {
+        fields
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| *value)
+            .ok_or_else(|| ParseError::at(paragraph, paragraph, format!("missing `{}:`", key)))
+    };
+
+    let items = field("Starting items")?;
+    let items = items
+        .split(',')
+        .filter(|worry| !worry.trim().is_empty())
+        .map(|worry| {
+            Ok(Item {
+                worry: parse::value(paragraph, worry)?,
+            })
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    let expression = field("Operation")?;
+    let operation = match strip(paragraph, expression, "new = old ")?.split_once(' ') {
+        Some(("*", "old")) => Operation::Pow(2),
+        Some(("*", value)) => Operation::Mul(parse::value(paragraph, value)?),
+        Some(("+", "old")) => Operation::Mul(2),
+        Some(("+", value)) => Operation::Add(parse::value(paragraph, value)?),
+        _ => {
+            let message = "expected `new = old * <value>` or `new = old + <value>`";
+            return Err(ParseError::at(paragraph, expression, message));
+        }
+    };
+
+    let divisor = strip(paragraph, field("Test")?, "divisible by ")?;
+    let decision_divisible_by = parse::value(paragraph, divisor)?;
+    if decision_divisible_by == 0 {
+        let message = "monkeys can not test for divisibility by 0";
+        return Err(ParseError::at(paragraph, divisor.trim(), message));
+    }
+
+    let target = |key: &str| {
+        let target = strip(paragraph, field(key)?, "throw to monkey ")?;
+        match parse::value(paragraph, target)? {
+            target if target < count && target != index => Ok(target),
+            other => Err(ParseError::at(
+                paragraph,
+                target.trim(),
+                format!("monkey {} can not throw to monkey {}", index, other),
+            )),
+        }
+    };
+
+    Ok(Monkey {
+        items,
+        operation,
+        decision_divisible_by,
+        true_target: target("If true")?,
+        false_target: target("If false")?,
+        item_inspection_count: 0,
+    })
+}
+
+pub struct Troop {
+    monkeys: HashMap<usize, Monkey>,
+    rounds: usize,
+    rounds_without_relief: usize,
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Troop;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "rounds",
+            default: "20",
+            description: "the rounds to simulate in part one",
+        },
+        Param {
+            name: "rounds_without_relief",
+            default: "10000",
+            description: "the rounds to simulate in part two, where worry levels are not divided",
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn check_params(params: &Params) -> Result<(), ParamError> {
+        params.try_get::<usize>("rounds")?;
+        params.try_get::<usize>("rounds_without_relief")?;
+        Ok(())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let count = parse::paragraphs(input).count();
+        let mut index = 0;
+        let monkeys = parse::parse_paragraphs(input, |paragraph| {
+            let monkey = parse_monkey(paragraph, index, count)?;
+            index += 1;
+            Ok(monkey)
+        })?;
+        let monkeys = monkeys.into_iter().enumerate().collect();
+        Ok(Troop {
+            monkeys,
+            rounds: params.try_get("rounds")?,
+            rounds_without_relief: params.try_get("rounds_without_relief")?,
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let mut monkeys = input.monkeys.clone();
+        execute(&mut monkeys, input.rounds, true);
+        monkey_business(&monkeys).map(Answer::from)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let mut monkeys = input.monkeys.clone();
+        execute(&mut monkeys, input.rounds_without_relief, false);
+        monkey_business(&monkeys).map(Answer::from)
+    }
+}
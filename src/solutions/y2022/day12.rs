@@ -0,0 +1,66 @@
+use crate::helpers::parse::ParseError;
+use crate::helpers::grid::{Grid, Pos};
+use crate::helpers::search::bfs_multi;
+use crate::{Answer, Solution};
+
+#[derive(Debug, PartialEq)]
+enum Tile {
+    Path(u32),
+    Start,
+    End,
+}
+
+#[derive(Debug)]
+pub struct HeightMap {
+    tiles: Grid<Tile>,
+}
+
+impl HeightMap {
+    fn neighbors(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let tile = &self.tiles[pos];
+        self.tiles
+            .neighbors4(pos)
+            .filter(move |neighbor| match (tile, &self.tiles[*neighbor]) {
+                (Tile::Start, Tile::Path(a)) => *a < 2,
+                (Tile::Path(a), Tile::Path(b)) => b < a || a.abs_diff(*b) < 2,
+                (Tile::Path(a), Tile::End) => *a > 24,
+                _ => false,
+            })
+    }
+
+    /// The fewest steps from any of `starts` to the end.
+    fn shortest_path(&self, starts: impl IntoIterator<Item = Pos>) -> Option<usize> {
+        let end = self.tiles.position(|tile| tile == &Tile::End)?;
+        bfs_multi(starts, |pos| self.neighbors(*pos), |pos| *pos == end).map(|found| found.cost)
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = HeightMap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let tiles = Grid::try_parse(input, |c| match c {
+            'S' => Ok(Tile::Start),
+            'E' => Ok(Tile::End),
+            'a'..='z' => Ok(Tile::Path(c as u32 - 'a' as u32)),
+            c => Err(format!("expected an elevation, found '{}'", c)),
+        })?;
+        Ok(HeightMap { tiles })
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let start = input.tiles.position(|tile| tile == &Tile::Start)?;
+        input.shortest_path([start]).map(Answer::from)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let lowest_points = input
+            .tiles
+            .iter()
+            .filter(|(_, tile)| matches!(tile, Tile::Start | Tile::Path(0)))
+            .map(|(pos, _)| pos);
+        input.shortest_path(lowest_points).map(Answer::from)
+    }
+}
@@ -0,0 +1,131 @@
+use std::cmp;
+
+use crate::helpers::parse::{self, ParseError};
+use crate::{Answer, Solution};
+
+type Packet = Option<serde_json::Value>;
+
+pub fn arr_len(a: &serde_json::Value) -> usize {
+    a.as_array().unwrap().len()
+}
+
+pub fn wrap_arr(a: &serde_json::Value) -> serde_json::Value {
+    serde_json::Value::Array(vec![a.clone()])
+}
+
+fn validate_pos(packet_a: &Packet, packet_b: &Packet) -> (bool, bool) {
+    match (packet_a, packet_b) {
+        (None, None) => unreachable!(),
+        (None, Some(_)) => (true, true),
+        (Some(_), None) => (false, true),
+        (Some(left), Some(right)) => {
+            if left.is_number() && right.is_number() {
+                let a = left.as_u64().unwrap();
+                let b = right.as_u64().unwrap();
+                (a <= b, a != b)
+            } else if left.is_number() && right.is_array() {
+                validate_pos(&Some(wrap_arr(left)), &Some(right.clone()))
+            } else if left.is_array() && right.is_number() {
+                validate_pos(&Some(left.clone()), &Some(wrap_arr(right)))
+            } else if left.is_array() && right.is_array() {
+                let mut is_valid = true;
+                let mut is_finished = false;
+                let mut i = 0;
+
+                let max_len = cmp::max(arr_len(left), arr_len(right));
+
+                while is_valid && !is_finished && i < max_len {
+                    (is_valid, is_finished) =
+                        validate_pos(&left.get(i).cloned(), &right.get(i).cloned());
+                    i += 1;
+                }
+
+                (is_valid, is_finished)
+            } else {
+                unreachable!()
+            }
+        }
+    }
+}
+
+/// Whether `value` is a list that only holds non-negative integers and such lists.
+fn is_packet(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Array(items) => items.iter().all(|item| item.is_u64() || is_packet(item)),
+        _ => false,
+    }
+}
+
+fn parse_packet(line: &str) -> Result<Packet, ParseError> {
+    match serde_json::from_str(line) {
+        Ok(value) if is_packet(&value) => Ok(Some(value)),
+        Ok(_) => Err(ParseError::at(line, line.trim_start(), "expected a list of integers and lists")),
+        Err(e) => Err(ParseError::new(
+            1,
+            e.column().max(1),
+            "expected a packet like `[1,[2,3]]`",
+        )),
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Packet>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let pairs = parse::parse_paragraphs(input, |pair| {
+            let packets = parse::parse_lines(pair, parse_packet)?;
+            match packets.len() {
+                2 => Ok(packets),
+                count => Err(ParseError::at(
+                    pair,
+                    pair,
+                    format!("expected a pair of packets, found {}", count),
+                )),
+            }
+        })?;
+        Ok(pairs.concat())
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        Some(
+            input
+                .chunks(2)
+                .enumerate()
+                .filter(|(_, pair)| validate_pos(&pair[0], &pair[1]).0)
+                .map(|(i, _)| i + 1)
+                .sum::<usize>()
+                .into(),
+        )
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let mut packets = input.clone();
+        let dividers = ["[[2]]".to_string(), "[[6]]".to_string()];
+        packets.extend(dividers.iter().map(|s| serde_json::from_str(s).ok()));
+
+        packets.sort_by(|a, b| {
+            let (valid, _) = validate_pos(a, b);
+            if valid {
+                cmp::Ordering::Less
+            } else {
+                cmp::Ordering::Greater
+            }
+        });
+
+        Some(
+            packets
+                .iter()
+                .enumerate()
+                .fold(1, |acc, (i, curr)| {
+                    if dividers.contains(&serde_json::to_string(curr).unwrap()) {
+                        acc * (i + 1)
+                    } else {
+                        acc
+                    }
+                })
+                .into(),
+        )
+    }
+}
@@ -0,0 +1,235 @@
+use crate::helpers::geometry::{Dir8, Point2};
+use crate::helpers::grid::SparseGrid;
+use crate::helpers::parse::{self, ParseError};
+use crate::params::{Param, ParamError, Params};
+use crate::props::{Generate, Reference, Rng};
+use crate::{Answer, Solution};
+
+/// Parses the `x,y` position `part`, a slice of `line`.
+fn parse_point(line: &str, part: &str) -> Result<Point2, ParseError> {
+    let (x, y) = part
+        .split_once(',')
+        .ok_or_else(|| ParseError::at(line, part.trim_start(), "expected an x,y position"))?;
+    Ok(Point2::new(parse::value(line, x)?, parse::value(line, y)?))
+}
+
+/// The position the sand pours in from, given by the `source` parameter.
+fn source(params: &Params) -> Result<Point2, ParamError> {
+    let value = params.get_str("source");
+    parse_point(value, value).map_err(|e| ParamError::invalid("source", value, e.message))
+}
+
+/// Sand tries to fall straight down first, then down to the left, then down to the right.
+const DROP_DIRECTIONS: [Dir8; 3] = [Dir8::S, Dir8::SW, Dir8::SE];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Rock,
+    Sand,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    tiles: SparseGrid<Tile>,
+    lowest_rock: i64,
+    source: Point2,
+}
+
+impl Cave {
+    fn valid_drop_destination(&self, start: Point2) -> Option<Point2> {
+        DROP_DIRECTIONS
+            .iter()
+            .map(|dir| start + dir.delta())
+            .find(|destination| !self.tiles.contains(*destination))
+    }
+
+    fn valid_drop_destination_with_floor(&self, start: Point2, floor: i64) -> Option<Point2> {
+        self.valid_drop_destination(start)
+            .filter(|destination| destination.y < floor)
+    }
+}
+
+fn sand_count(cave: &Cave) -> usize {
+    cave.tiles
+        .iter()
+        .filter(|(_, tile)| **tile == Tile::Sand)
+        .count()
+}
+
+fn simulate_sandfall(cave: &Cave) -> usize {
+    let mut cave = cave.clone();
+    let mut sand_coord = cave.source;
+    let mut last_sand_coord = Point2::ORIGIN;
+    while last_sand_coord != sand_coord && sand_coord.y < cave.lowest_rock {
+        last_sand_coord = sand_coord;
+        match cave.valid_drop_destination(sand_coord) {
+            Some(destination) => {
+                sand_coord = destination;
+            }
+            None => {
+                // sand has come to rest
+                cave.tiles.insert(sand_coord, Tile::Sand);
+                sand_coord = cave.source;
+            }
+        }
+    }
+    sand_count(&cave)
+}
+
+fn simulate_sandfall_with_floor(cave: &Cave) -> usize {
+    let mut cave = cave.clone();
+    let mut sand_coord = cave.source;
+    let mut last_sand_coord = Point2::ORIGIN;
+    while last_sand_coord != cave.source || sand_coord != cave.source {
+        last_sand_coord = sand_coord;
+        match cave.valid_drop_destination_with_floor(sand_coord, cave.lowest_rock + 2) {
+            Some(destination) => {
+                sand_coord = destination;
+            }
+            None => {
+                // sand has come to rest
+                cave.tiles.insert(sand_coord, Tile::Sand);
+                sand_coord = cave.source;
+            }
+        }
+    }
+    sand_count(&cave)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Cave;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "source",
+        default: "500,0",
+        description: "the x,y position the sand pours in from",
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn check_params(params: &Params) -> Result<(), ParamError> {
+        source(params).map(|_| ())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let paths = parse::parse_lines(input, |line| {
+            line.split("->")
+                .map(|point| parse_point(line, point))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+        if paths.is_empty() {
+            return Err(ParseError::new(1, 1, "expected at least one rock path"));
+        }
+        let mut tiles = SparseGrid::new();
+        for points in paths {
+            // a path of a single point is a single rock
+            tiles.insert(points[0], Tile::Rock);
+            for pair in points.windows(2) {
+                let (mut point, end) = (pair[0], pair[1]);
+                let step = (end - point).signum();
+                while point != end {
+                    point += step;
+                    tiles.insert(point, Tile::Rock);
+                }
+            }
+        }
+        let (_, max) = tiles.bounds().unwrap();
+        Ok(Cave {
+            tiles,
+            lowest_rock: max.y,
+            source: source(params)?,
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        Some(simulate_sandfall(input).into())
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        Some(simulate_sandfall_with_floor(input).into())
+    }
+}
+
+impl Generate for Day14 {
+    fn generate(rng: &mut Rng) -> String {
+        let paths = rng.range(1..=5);
+        (0..paths)
+            .map(|_| {
+                let (mut x, mut y) = (rng.range(490..=510), rng.range(2..=15));
+                let mut points = vec![format!("{},{}", x, y)];
+                for _ in 0..rng.range(1..=3) {
+                    if rng.chance(50) {
+                        x = (x + rng.range(-6..=6)).clamp(480, 520);
+                    } else {
+                        y = (y + rng.range(-6..=6)).clamp(1, 20);
+                    }
+                    points.push(format!("{},{}", x, y));
+                }
+                points.join(" -> ") + "\n"
+            })
+            .collect()
+    }
+}
+
+/// Drops one grain at a time into a grid of the whole cave and counts the grains at rest, until
+/// one falls past the lowest rock or, with the floor, the source is covered.
+fn reference_sand_count(input: &str, params: &Params, with_floor: bool) -> usize {
+    let (source_x, source_y) = params.get_str("source").split_once(',').unwrap();
+    let (source_x, source_y): (usize, usize) = (source_x.parse().unwrap(), source_y.parse().unwrap());
+    let paths: Vec<Vec<(usize, usize)>> = input
+        .lines()
+        .map(|line| {
+            line.split(" -> ")
+                .map(|point| {
+                    let (x, y) = point.split_once(',').unwrap();
+                    (x.parse().unwrap(), y.parse().unwrap())
+                })
+                .collect()
+        })
+        .collect();
+    let lowest = paths.iter().flatten().map(|&(_, y)| y).max().unwrap();
+    let floor = lowest + 2;
+    let mut blocked = vec![vec![false; 1000]; floor + 1];
+    for path in &paths {
+        for pair in path.windows(2) {
+            let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+            for row in blocked.iter_mut().take(y1.max(y2) + 1).skip(y1.min(y2)) {
+                row[x1.min(x2)..=x1.max(x2)].fill(true);
+            }
+        }
+    }
+    if with_floor {
+        blocked[floor].fill(true);
+    }
+
+    let mut grains = 0;
+    while !blocked[source_y][source_x] {
+        let (mut x, mut y) = (source_x, source_y);
+        loop {
+            if y == floor {
+                return grains;
+            }
+            match [x, x - 1, x + 1].into_iter().find(|&x| !blocked[y + 1][x]) {
+                Some(next) => (x, y) = (next, y + 1),
+                None => break,
+            }
+        }
+        blocked[y][x] = true;
+        grains += 1;
+    }
+    grains
+}
+
+impl Reference for Day14 {
+    fn reference_one(input: &str, params: &Params) -> Option<Answer> {
+        Some(reference_sand_count(input, params, false).into())
+    }
+
+    fn reference_two(input: &str, params: &Params) -> Option<Answer> {
+        Some(reference_sand_count(input, params, true).into())
+    }
+}
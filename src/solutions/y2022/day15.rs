@@ -0,0 +1,314 @@
+use crate::helpers::geometry::{Interval, Point2};
+use crate::helpers::parse::{self, ParseError};
+use crate::params::{Param, ParamError, Params};
+use crate::props::{Generate, Reference, Rng};
+use crate::{Answer, Solution};
+
+#[derive(Debug)]
+pub struct Sensor {
+    position: Point2,
+    beacon: Point2,
+    reach: i64,
+}
+
+pub struct Scan {
+    sensors: Vec<Sensor>,
+    row: i64,
+    max_coordinate: u64,
+}
+
+/// The two ends of a sensor's edge and its slope, which is known even when both ends are the
+/// same point because the sensor sits on its beacon.
+type Edge = (Point2, Point2, i64);
+
+fn sensor_reaches_at_row(sensors: &[Sensor], y: i64) -> Vec<Interval> {
+    sensors
+        .iter()
+        .filter_map(
+            |Sensor {
+                 position,
+                 beacon: _,
+                 reach,
+             }| {
+                let half_size = reach - (y - position.y).abs();
+                (half_size >= 0)
+                    .then(|| Interval::new(position.x - half_size, position.x + half_size))
+            },
+        )
+        .collect()
+}
+
+fn get_row_coverage(sensors: &[Sensor], row: i64) -> u64 {
+    let merged = Interval::merge(sensor_reaches_at_row(sensors, row));
+
+    // Sum the sizes of all these ranges, taking care of removing
+    // each beacon known to be on this line
+    let mut included_beacons = Vec::with_capacity(sensors.len());
+    merged
+        .iter()
+        .map(|reach| {
+            // there can be multiple beacons on a line
+            beacons_in_reach(sensors, reach, row, &mut included_beacons);
+            reach.size() - included_beacons.len() as u64
+        })
+        .sum()
+}
+
+fn beacons_in_reach(sensors: &[Sensor], reach: &Interval, y: i64, into_vec: &mut Vec<Point2>) {
+    into_vec.clear();
+    for s in sensors {
+        if s.beacon.y == y && reach.contains(s.beacon.x) && !into_vec.contains(&s.beacon) {
+            into_vec.push(s.beacon);
+        }
+    }
+}
+
+fn find_signal_freq(sensors: &[Sensor], max_pos: u64) -> Option<u64> {
+    let max_pos = max_pos as i64;
+    let edges = edges(sensors);
+    let mut interesting_ys = Vec::with_capacity(edges.len() * edges.len() * 4);
+    for i in 0..edges.len() {
+        for j in 0..edges.len() {
+            interesting_ys.extend(points_of_interest(&edges, i, j).into_iter().flatten());
+        }
+    }
+    // A distress beacon on the border of the area is only enclosed by the border and an edge
+    // that crosses it right above or below.
+    for &edge in &edges {
+        let (a, b) = line_params(edge);
+        for x in [0, max_pos] {
+            interesting_ys.extend([a * x + b - 1, a * x + b + 1]);
+        }
+    }
+    interesting_ys.extend([0, max_pos]);
+    interesting_ys.retain(|y| (0..=max_pos).contains(y));
+    interesting_ys.sort_unstable();
+    interesting_ys.dedup();
+
+    for y in interesting_ys {
+        let merged = Interval::merge(sensor_reaches_at_row(sensors, y));
+        if let Some(x) = first_gap(&merged, max_pos) {
+            return Some(4000000 * x as u64 + y as u64);
+        }
+    }
+    // the sensors cover the whole area
+    None
+}
+
+/// The first x in `0..=max_pos` that none of the merged `reaches` contains.
+fn first_gap(reaches: &[Interval], max_pos: i64) -> Option<i64> {
+    let mut x = 0;
+    for reach in reaches {
+        if reach.start > x {
+            break;
+        }
+        x = x.max(reach.end + 1);
+    }
+    (x <= max_pos).then_some(x)
+}
+
+fn edges(sensors: &[Sensor]) -> Vec<Edge> {
+    let mut edges = Vec::with_capacity(sensors.len() * 4);
+    for Sensor {
+        position: p,
+        reach,
+        beacon: _,
+    } in sensors
+    {
+        let left = Point2::new(p.x - reach, p.y);
+        let right = Point2::new(p.x + reach, p.y);
+        let top = Point2::new(p.x, p.y - reach);
+        let bottom = Point2::new(p.x, p.y + reach);
+        edges.push((left, top, -1));
+        edges.push((bottom, right, -1));
+        edges.push((top, right, 1));
+        edges.push((left, bottom, 1));
+    }
+    edges
+}
+fn points_of_interest(edges: &[Edge], index1: usize, index2: usize) -> [Option<i64>; 2] {
+    if index1 == index2 {
+        return [None; 2];
+    }
+    // we've been adding edges two by two having the same direction
+    let edge1 = edges[index1];
+    let edge2 = edges[index2];
+    let params1 = line_params(edge1);
+    let params2 = line_params(edge2);
+    if params1.0 == params2.0 {
+        // parallels
+        return [None; 2];
+    }
+    let [y1, y2] = y_intersection(params1, params2);
+    [
+        y1.and_then(|y| (edge_contains_y(edge1, y) && edge_contains_y(edge2, y)).then_some(y)),
+        y2.and_then(|y| (edge_contains_y(edge1, y) && edge_contains_y(edge2, y)).then_some(y)),
+    ]
+}
+
+fn edge_contains_y((p1, p2, _): Edge, y: i64) -> bool {
+    y >= p1.y.min(p2.y) && y <= p1.y.max(p2.y)
+}
+
+fn line_params((p1, _, a): Edge) -> (i64, i64) {
+    let b = p1.y - a * p1.x;
+    (a, b)
+}
+
+fn y_intersection((a1, b1): (i64, i64), (a2, b2): (i64, i64)) -> [Option<i64>; 2] {
+    let top = a1 * b2 - a2 * b1;
+    let bottom = a1 - a2;
+    if top % bottom == 0 {
+        [Some(top / bottom), None]
+    } else {
+        let result = top / bottom;
+        [Some(result), Some(result + 1)]
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Scan;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "row",
+            default: "2000000",
+            description: "the row to count covered positions in",
+        },
+        Param {
+            name: "max_coordinate",
+            default: "4000000",
+            description: "the largest x and y of the distress beacon",
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn check_params(params: &Params) -> Result<(), ParamError> {
+        params.try_get::<i64>("row")?;
+        let max_coordinate: u64 = params.try_get("max_coordinate")?;
+        if max_coordinate > 4000000 {
+            let reason = "the tuning frequency only works for coordinates up to 4000000";
+            return Err(ParamError::invalid("max_coordinate", params.get_str("max_coordinate"), reason));
+        }
+        Ok(())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let sensors = parse::parse_lines(input, |line| {
+            let [sensor_x, sensor_y, beacon_x, beacon_y] = parse::ints_array(line)?;
+            let position = Point2::new(sensor_x, sensor_y);
+            let beacon = Point2::new(beacon_x, beacon_y);
+            let reach = position.manhattan(beacon);
+            Ok(Sensor {
+                position,
+                beacon,
+                reach,
+            })
+        })?;
+        Ok(Scan {
+            sensors,
+            row: params.try_get("row")?,
+            max_coordinate: params.try_get("max_coordinate")?,
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let row_coverage = get_row_coverage(&input.sensors, input.row);
+        Some(row_coverage.into())
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        find_signal_freq(&input.sensors, input.max_coordinate).map(Answer::from)
+    }
+}
+
+impl Generate for Day15 {
+    const GENERATED_PARAMS: &'static [(&'static str, &'static str)] =
+        &[("row", "10"), ("max_coordinate", "20")];
+
+    /// Hides a distress beacon in the 21x21 search area and adds sensors that cannot see it until
+    /// every other position of the area is covered.
+    fn generate(rng: &mut Rng) -> String {
+        let distress = Point2::new(rng.range(0..=20), rng.range(0..=20));
+        let area: Vec<Point2> = (0..=20)
+            .flat_map(|y| (0..=20).map(move |x| Point2::new(x, y)))
+            .filter(|&p| p != distress)
+            .collect();
+        let mut sensors: Vec<(Point2, Point2)> = Vec::new();
+        let covered = |sensors: &[(Point2, Point2)], p: Point2| {
+            sensors.iter().any(|(s, b)| s.manhattan(p) <= s.manhattan(*b))
+        };
+        while area.iter().any(|&p| !covered(&sensors, p)) {
+            let sensor = Point2::new(rng.range(-5..=25), rng.range(-5..=25));
+            let distance = sensor.manhattan(distress);
+            if distance < 2 {
+                continue;
+            }
+            let reach = rng.range(distance / 2..=distance - 1).max(1);
+            let dx = rng.range(-reach..=reach);
+            let dy = (reach - dx.abs()) * *rng.choose(&[-1, 1]);
+            let beacon = sensor + Point2::new(dx, dy);
+            // Only keep sensors that cover something new, so inputs stay small.
+            if area
+                .iter()
+                .any(|&p| !covered(&sensors, p) && sensor.manhattan(p) <= reach)
+            {
+                sensors.push((sensor, beacon));
+            }
+        }
+        sensors
+            .iter()
+            .map(|(s, b)| {
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                    s.x, s.y, b.x, b.y
+                )
+            })
+            .collect()
+    }
+}
+
+/// Sensors and their reach, for checking every position one by one.
+fn reference_sensors(input: &str) -> Option<Vec<[i64; 5]>> {
+    input
+        .lines()
+        .map(|line| {
+            let [sx, sy, bx, by] = parse::ints_array::<i64, 4>(line).ok()?;
+            Some([sx, sy, bx, by, (sx - bx).abs() + (sy - by).abs()])
+        })
+        .collect()
+}
+
+fn reference_covered(sensors: &[[i64; 5]], x: i64, y: i64) -> bool {
+    sensors
+        .iter()
+        .any(|[sx, sy, _, _, reach]| (sx - x).abs() + (sy - y).abs() <= *reach)
+}
+
+impl Reference for Day15 {
+    fn reference_one(input: &str, params: &Params) -> Option<Answer> {
+        let sensors = reference_sensors(input)?;
+        let row: i64 = params.get("row");
+        let min_x = sensors.iter().map(|[sx, _, _, _, r]| sx - r).min()?;
+        let max_x = sensors.iter().map(|[sx, _, _, _, r]| sx + r).max()?;
+        let count = (min_x..=max_x)
+            .filter(|&x| reference_covered(&sensors, x, row))
+            .filter(|&x| !sensors.iter().any(|s| s[2] == x && s[3] == row))
+            .count();
+        Some(count.into())
+    }
+
+    fn reference_two(input: &str, params: &Params) -> Option<Answer> {
+        let sensors = reference_sensors(input)?;
+        let max: i64 = params.get("max_coordinate");
+        (0..=max)
+            .flat_map(|y| (0..=max).map(move |x| (x, y)))
+            .find(|&(x, y)| !reference_covered(&sensors, x, y))
+            .map(|(x, y)| (4000000 * x + y).into())
+    }
+}
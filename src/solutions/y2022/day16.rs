@@ -0,0 +1,316 @@
+use std::{cmp, collections::HashMap, collections::HashSet};
+
+use crate::helpers::parse::{self, lazy_regex, ParseError};
+use crate::helpers::search::all_pairs;
+use crate::params::{Param, ParamError, Params};
+use crate::props::{Generate, Reference, Rng};
+use crate::{Answer, Solution};
+
+pub struct Valve {
+    flow: i64,
+    mask: i64,
+    tunnels: Vec<String>,
+}
+
+type ValveMap = HashMap<String, Valve>;
+type DistanceMap = HashMap<(String, String), i64>;
+
+pub struct Cave {
+    valve_map: ValveMap,
+    distances: DistanceMap,
+    start: String,
+    minutes: i64,
+    minutes_with_elephant: i64,
+}
+
+fn calc_distances(valve_map: &ValveMap) -> DistanceMap {
+    let valves: Vec<String> = valve_map.keys().cloned().collect();
+    all_pairs(&valves, |valve| {
+        valve_map[valve]
+            .tunnels
+            .iter()
+            .map(|tunnel| (tunnel.clone(), 1))
+            .collect::<Vec<_>>()
+    })
+}
+
+fn visit<'a>(
+    valve: String,
+    budget: i64,
+    state: i64,
+    valve_map: &ValveMap,
+    distances: &DistanceMap,
+    flow: i64,
+    answer: &'a mut HashMap<i64, i64>,
+) -> &'a mut HashMap<i64, i64> {
+    let n = if !answer.contains_key(&state) {
+        0
+    } else {
+        *answer.get(&state).unwrap()
+    };
+    answer.insert(state, cmp::max(n, flow));
+    for k in valve_map
+        .iter()
+        .filter(|(_, cv)| cv.flow > 0)
+        .map(|(ck, _)| ck)
+    {
+        let Some(dist) = distances.get(&(valve.clone(), k.clone())) else {
+            continue;
+        };
+        let new_budget = budget - dist - 1;
+        let mask = valve_map.get(k).unwrap().mask;
+        if (state & mask) != 0 || new_budget < 0 {
+            continue;
+        } else {
+            let flow_here = valve_map.get(k).unwrap().flow;
+            let _ = visit(
+                k.clone(),
+                new_budget,
+                state | mask,
+                valve_map,
+                distances,
+                flow + (new_budget * flow_here),
+                answer,
+            );
+        }
+    }
+    answer
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Cave;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "start",
+            default: "AA",
+            description: "the valve to start at",
+        },
+        Param {
+            name: "minutes",
+            default: "30",
+            description: "the time until the volcano erupts in part one",
+        },
+        Param {
+            name: "minutes_with_elephant",
+            default: "26",
+            description: "the time left in part two after teaching the elephant",
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn check_params(params: &Params) -> Result<(), ParamError> {
+        params.try_get::<u32>("minutes")?;
+        params.try_get::<u32>("minutes_with_elephant")?;
+        Ok(())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let valve_regex = lazy_regex!(r"Valve (?P<name>-?[A-Z]{2}) has flow rate=(?P<flow>-?\d+); tunnels? leads? to valves? (?P<tunnels>-?.+)");
+        let mut i: u32 = 0;
+        let valves = parse::parse_lines(input, |line| {
+            let captures = parse::captures(valve_regex, line)?;
+            let name = captures["name"].to_string();
+            let flow = parse::value(line, &captures["flow"])?;
+            let tunnels = captures["tunnels"]
+                .split(", ")
+                .map(|s| s.to_string())
+                .collect();
+            let valve: Valve = Valve {
+                flow,
+                mask: i64::pow(2, i),
+                tunnels,
+            };
+            i += 1;
+            Ok((name, valve))
+        })?;
+        let valve_map: ValveMap = valves.into_iter().collect();
+        let distances = calc_distances(&valve_map);
+        Ok(Cave {
+            valve_map,
+            distances,
+            start: params.get_str("start").to_string(),
+            minutes: params.try_get::<u32>("minutes")?.into(),
+            minutes_with_elephant: params.try_get::<u32>("minutes_with_elephant")?.into(),
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let state = 0;
+        let mut answer = HashMap::new();
+        let final_answer = visit(
+            input.start.clone(),
+            input.minutes,
+            state,
+            &input.valve_map,
+            &input.distances,
+            0,
+            &mut answer,
+        );
+        final_answer.values().cloned().max().map(Answer::from)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let state: i64 = 0;
+        let mut answer = HashMap::new();
+        let final_answer = visit(
+            input.start.clone(),
+            input.minutes_with_elephant,
+            state,
+            &input.valve_map,
+            &input.distances,
+            0,
+            &mut answer,
+        );
+        let mut total = 0;
+        for (k1, v1) in final_answer.iter() {
+            for (k2, v2) in final_answer.iter() {
+                if (k1 & k2) == 0 && v1 + v2 > total {
+                    total = v1 + v2;
+                }
+            }
+        }
+        Some(total.into())
+    }
+}
+
+impl Generate for Day16 {
+    /// A connected cave of up to seven valves, two thirds of them with a flow rate.
+    fn generate(rng: &mut Rng) -> String {
+        let count = rng.range(2..=7) as usize;
+        let mut names = vec!["AA".to_string()];
+        while names.len() < count {
+            let mut letter = || char::from(b'A' + rng.below(26) as u8);
+            let name: String = [letter(), letter()].into_iter().collect();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        let mut tunnels = vec![HashSet::new(); count];
+        for valve in 1..count {
+            let other = rng.below(valve);
+            tunnels[valve].insert(other);
+            tunnels[other].insert(valve);
+        }
+        for _ in 0..rng.below(count) {
+            let (a, b) = (rng.below(count), rng.below(count));
+            if a != b {
+                tunnels[a].insert(b);
+                tunnels[b].insert(a);
+            }
+        }
+        (0..count)
+            .map(|valve| {
+                let flow = if rng.chance(66) { rng.range(1..=25) } else { 0 };
+                let mut to: Vec<&str> = tunnels[valve].iter().map(|&t| names[t].as_str()).collect();
+                to.sort();
+                let leads = if to.len() == 1 {
+                    "tunnel leads to valve"
+                } else {
+                    "tunnels lead to valves"
+                };
+                format!(
+                    "Valve {} has flow rate={}; {} {}\n",
+                    names[valve],
+                    flow,
+                    leads,
+                    to.join(", ")
+                )
+            })
+            .collect()
+    }
+}
+
+/// The valves of a cave by index, with their flow rate and the indices of their neighbors.
+struct ReferenceCave {
+    flows: Vec<i64>,
+    tunnels: Vec<Vec<usize>>,
+    start: usize,
+}
+
+impl ReferenceCave {
+    fn parse(input: &str, params: &Params) -> Self {
+        let lines: Vec<(&str, i64, Vec<&str>)> = input
+            .lines()
+            .map(|line| {
+                let words: Vec<&str> = line.split([' ', '=', ';', ',']).collect();
+                let tunnels = words[11..].iter().copied().filter(|w| !w.is_empty());
+                (words[1], words[5].parse().unwrap(), tunnels.collect())
+            })
+            .collect();
+        let index = |name: &str| lines.iter().position(|(n, _, _)| *n == name).unwrap();
+        ReferenceCave {
+            flows: lines.iter().map(|(_, flow, _)| *flow).collect(),
+            tunnels: lines
+                .iter()
+                .map(|(_, _, tunnels)| tunnels.iter().map(|t| index(t)).collect())
+                .collect(),
+            start: index(params.get_str("start")),
+        }
+    }
+
+    /// The most pressure that can still be released by the actors at `positions` in `minutes`,
+    /// trying every combination of moves and opened valves minute by minute.
+    fn best(
+        &self,
+        positions: Vec<usize>,
+        opened: u64,
+        minutes: i64,
+        memo: &mut HashMap<(Vec<usize>, u64, i64), i64>,
+    ) -> i64 {
+        if minutes == 0 {
+            return 0;
+        }
+        let key = (positions.clone(), opened, minutes);
+        if let Some(&best) = memo.get(&key) {
+            return best;
+        }
+        // every actor either opens the valve it stands at or moves through a tunnel
+        let mut outcomes: Vec<(Vec<usize>, u64, i64)> = vec![(vec![], opened, 0)];
+        for &position in &positions {
+            let mut next = Vec::new();
+            for (moved, opened, released) in outcomes {
+                let bit = 1 << position;
+                if self.flows[position] > 0 && opened & bit == 0 {
+                    let mut moved = moved.clone();
+                    moved.push(position);
+                    let pressure = self.flows[position] * (minutes - 1);
+                    next.push((moved, opened | bit, released + pressure));
+                }
+                for &tunnel in &self.tunnels[position] {
+                    let mut moved = moved.clone();
+                    moved.push(tunnel);
+                    next.push((moved, opened, released));
+                }
+            }
+            outcomes = next;
+        }
+        let best = outcomes
+            .into_iter()
+            .map(|(moved, opened, released)| released + self.best(moved, opened, minutes - 1, memo))
+            .max()
+            .unwrap_or(0);
+        memo.insert(key, best);
+        best
+    }
+}
+
+impl Reference for Day16 {
+    fn reference_one(input: &str, params: &Params) -> Option<Answer> {
+        let cave = ReferenceCave::parse(input, params);
+        let minutes = params.get("minutes");
+        Some(cave.best(vec![cave.start], 0, minutes, &mut HashMap::new()).into())
+    }
+
+    fn reference_two(input: &str, params: &Params) -> Option<Answer> {
+        let cave = ReferenceCave::parse(input, params);
+        let minutes = params.get("minutes_with_elephant");
+        let positions = vec![cave.start, cave.start];
+        Some(cave.best(positions, 0, minutes, &mut HashMap::new()).into())
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use crate::helpers::geometry::{Dir4, Point2};
+use crate::helpers::grid::SparseGrid;
+use crate::helpers::parse::ParseError;
+use crate::params::{Param, ParamError, Params};
+use crate::{Answer, Solution};
+
+#[derive(Debug)]
+enum RockShape {
+    HorizontalLine,
+    Diamond,
+    BackwardsL,
+    VerticalLine,
+    Square,
+}
+
+impl RockShape {
+    /// The cells of the rock relative to its bottom left corner; `y` grows upwards.
+    fn offsets(&self) -> &'static [(i64, i64)] {
+        match self {
+            RockShape::HorizontalLine => &[(0, 0), (1, 0), (2, 0), (3, 0)],
+            RockShape::Diamond => &[(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)],
+            RockShape::BackwardsL => &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            RockShape::VerticalLine => &[(0, 0), (0, 1), (0, 2), (0, 3)],
+            RockShape::Square => &[(0, 0), (0, 1), (1, 0), (1, 1)],
+        }
+    }
+
+    fn covered_coords(&self, corner: Point2) -> impl Iterator<Item = Point2> {
+        self.offsets()
+            .iter()
+            .map(move |offset| corner + Point2::from(*offset))
+    }
+
+    fn x_max(&self, corner: Point2) -> i64 {
+        self.covered_coords(corner).map(|p| p.x).max().unwrap()
+    }
+
+    fn y_max(&self, corner: Point2) -> i64 {
+        self.covered_coords(corner).map(|p| p.y).max().unwrap()
+    }
+
+    /// Whether the rock would overlap a rock at rest or the floor.
+    fn has_collisions(&self, corner: Point2, rocks: &SparseGrid<()>) -> bool {
+        self.covered_coords(corner)
+            .any(|p| p.y < 0 || rocks.contains(p))
+    }
+}
+
+const FALL_ORDER: &[RockShape; 5] = {
+    use RockShape::*;
+    &[HorizontalLine, Diamond, BackwardsL, VerticalLine, Square]
+};
+
+/// Rocks appear two units away from the left wall.
+const LEFT_OFFSET: i64 = 2;
+
+pub struct Chamber {
+    directions: Vec<Dir4>,
+    width: i64,
+}
+
+/// The state of the chamber right before a new rock starts falling. Once a state repeats,
+/// every rock in between will repeat too, so the tower grows by a fixed height per cycle.
+type Fingerprint = (usize, usize, Vec<i64>);
+
+/// Columns deeper than this below the top count as equally deep. Without a limit, a column
+/// that no rock ever reaches, e.g. with a jet pattern that always pushes left, would make every
+/// fingerprint unique and no cycle would ever be found.
+const PROFILE_DEPTH: i64 = 64;
+
+fn surface_profile(column_heights: &[i64], highest_point: i64) -> Vec<i64> {
+    column_heights
+        .iter()
+        .map(|height| (highest_point - height).min(PROFILE_DEPTH))
+        .collect()
+}
+
+fn simulate(chamber: &Chamber, rock_count: i64) -> i64 {
+    let directions = &chamber.directions;
+    let mut dir_idx = 0;
+    let mut chamber_state = SparseGrid::new();
+    let mut column_heights = vec![0; chamber.width as usize];
+    let mut seen_states: HashMap<Fingerprint, (i64, i64)> = HashMap::new();
+    let mut highest_point: i64 = 0;
+    let mut skipped_height: i64 = 0;
+    let mut rock_idx: i64 = 0;
+    while rock_idx < rock_count {
+        let shape_idx = (rock_idx % FALL_ORDER.len() as i64) as usize;
+        if skipped_height == 0 {
+            let fingerprint = (
+                shape_idx,
+                dir_idx,
+                surface_profile(&column_heights, highest_point),
+            );
+            if let Some((cycle_start, cycle_start_height)) = seen_states.get(&fingerprint) {
+                let cycle_length = rock_idx - cycle_start;
+                let cycle_height = highest_point - cycle_start_height;
+                let cycles = (rock_count - rock_idx) / cycle_length;
+                rock_idx += cycles * cycle_length;
+                skipped_height = cycles * cycle_height;
+                if rock_idx == rock_count {
+                    continue;
+                }
+            } else {
+                seen_states.insert(fingerprint, (rock_idx, highest_point));
+            }
+        }
+        let shape = &FALL_ORDER[shape_idx];
+        let mut corner = Point2::new(LEFT_OFFSET, highest_point + 3);
+        loop {
+            let jet = directions[dir_idx];
+            dir_idx = (dir_idx + 1) % directions.len();
+            let pushed = corner + jet.delta();
+            if pushed.x >= 0
+                && shape.x_max(pushed) < chamber.width
+                && !shape.has_collisions(pushed, &chamber_state)
+            {
+                corner = pushed;
+            }
+            let dropped = corner - Point2::new(0, 1);
+            if shape.has_collisions(dropped, &chamber_state) {
+                for p in shape.covered_coords(corner) {
+                    let column_height = &mut column_heights[p.x as usize];
+                    *column_height = (*column_height).max(p.y + 1);
+                }
+                chamber_state.extend(shape.covered_coords(corner).map(|p| (p, ())));
+                highest_point = highest_point.max(shape.y_max(corner) + 1);
+                break;
+            }
+            corner = dropped;
+        }
+        rock_idx += 1;
+    }
+    highest_point + skipped_height
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Chamber;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "width",
+        default: "7",
+        description: "the width of the chamber",
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn check_params(params: &Params) -> Result<(), ParamError> {
+        let width: i64 = params.try_get("width")?;
+        let min_width = FALL_ORDER
+            .iter()
+            .map(|shape| shape.x_max(Point2::new(LEFT_OFFSET, 0)) + 1)
+            .max()
+            .unwrap();
+        if width < min_width {
+            let reason = format!("the chamber has to be at least {} wide to fit every rock where it appears", min_width);
+            return Err(ParamError::invalid("width", params.get_str("width"), reason));
+        }
+        Ok(())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let pattern = input.trim_end();
+        let directions = pattern
+            .char_indices()
+            .map(|(offset, c)| match c {
+                '<' => Ok(Dir4::Left),
+                '>' => Ok(Dir4::Right),
+                c => Err(ParseError::at(
+                    pattern,
+                    &pattern[offset..],
+                    format!("expected `<` or `>`, found {:?}", c),
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if directions.is_empty() {
+            return Err(ParseError::new(1, 1, "expected a jet pattern of `<` and `>`"));
+        }
+        Ok(Chamber {
+            directions,
+            width: params.try_get("width")?,
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        Some(simulate(input, 2022).into())
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        Some(simulate(input, 1_000_000_000_000).into())
+    }
+}
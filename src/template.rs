@@ -0,0 +1,70 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TemplateError {
+    #[error("unknown placeholder {{{{{0}}}}} on line {1}")]
+    UnknownPlaceholder(String, usize),
+    #[error("unclosed placeholder on line {0}")]
+    Unclosed(usize),
+}
+
+/// Replaces every `{{name}}` in `template` with the value of `name`. Whitespace inside the
+/// braces is ignored, and unknown placeholders are an error rather than being left in place.
+/// Write `\{{` for a literal `{{`.
+pub fn render(template: &str, values: &HashMap<&str, String>) -> Result<String, TemplateError> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        if let Some(before) = rest[..start].strip_suffix('\\') {
+            rendered.push_str(before);
+            rendered.push_str("{{");
+            rest = &rest[start + 2..];
+            continue;
+        }
+        let offset = template.len() - rest.len() + start;
+        let line = template[..offset].matches('\n').count() + 1;
+        rendered.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find("}}")
+            .ok_or(TemplateError::Unclosed(line))?;
+        let name = rest[start + 2..start + end].trim();
+        match values.get(name) {
+            Some(value) => rendered.push_str(value),
+            None => return Err(TemplateError::UnknownPlaceholder(name.to_string(), line)),
+        }
+        rest = &rest[start + end + 2..];
+    }
+
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let values = HashMap::from([("day", "7".to_string()), ("day_padded", "07".to_string())]);
+        assert_eq!(
+            render("pub struct Day{{day_padded}}; // DAY {{ day }}", &values),
+            Ok("pub struct Day07; // DAY 7".to_string())
+        );
+        assert_eq!(
+            render("a\n{{year}}", &values),
+            Err(TemplateError::UnknownPlaceholder("year".to_string(), 2))
+        );
+        assert_eq!(render("{{day", &values), Err(TemplateError::Unclosed(1)));
+        assert_eq!(
+            render(r#"println!("\{{}}", {{day}});"#, &values),
+            Ok(r#"println!("{{}}", 7);"#.to_string())
+        );
+    }
+}
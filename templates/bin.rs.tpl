@@ -0,0 +1,5 @@
+use advent_of_code::solutions::y{{year}}::day{{day_padded}}::Day{{day_padded}};
+
+fn main() {
+    advent_of_code::solve!(Day{{day_padded}}, {{year}}, {{day}});
+}
@@ -0,0 +1,6 @@
+#![no_main]
+
+use advent_of_code::solutions::y{{year}}::day{{day_padded}}::Day{{day_padded}};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| advent_of_code::props::fuzz::<Day{{day_padded}}>(data));
@@ -0,0 +1,20 @@
+use crate::helpers::parse::ParseError;
+use crate::{Answer, Solution};
+
+pub struct Day{{day_padded}};
+
+impl Solution for Day{{day_padded}} {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(_input: &Self::Input) -> Option<Answer> {
+        None::<{{answer_type}}>.map(Answer::from)
+    }
+
+    fn part_two(_input: &Self::Input) -> Option<Answer> {
+        None::<{{answer_type}}>.map(Answer::from)
+    }
+}
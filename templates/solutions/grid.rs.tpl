@@ -0,0 +1,22 @@
+use crate::helpers::parse::ParseError;
+use crate::helpers::Grid;
+use crate::{Answer, Solution};
+
+pub struct Day{{day_padded}};
+
+impl Solution for Day{{day_padded}} {
+    /// The puzzle input as a char map, see `helpers::grid` for neighbors, rows, columns and rays.
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.parse()?)
+    }
+
+    fn part_one(_input: &Self::Input) -> Option<Answer> {
+        None::<{{answer_type}}>.map(Answer::from)
+    }
+
+    fn part_two(_input: &Self::Input) -> Option<Answer> {
+        None::<{{answer_type}}>.map(Answer::from)
+    }
+}
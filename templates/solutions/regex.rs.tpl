@@ -0,0 +1,29 @@
+use crate::helpers::parse::{self, ParseError};
+use crate::{Answer, Solution};
+
+#[derive(Debug)]
+pub struct Entry {
+    pub values: Vec<i64>,
+}
+
+pub struct Day{{day_padded}};
+
+impl Solution for Day{{day_padded}} {
+    type Input = Vec<Entry>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, |line| {
+            Ok(Entry {
+                values: parse::ints(line)?,
+            })
+        })
+    }
+
+    fn part_one(_input: &Self::Input) -> Option<Answer> {
+        None::<{{answer_type}}>.map(Answer::from)
+    }
+
+    fn part_two(_input: &Self::Input) -> Option<Answer> {
+        None::<{{answer_type}}>.map(Answer::from)
+    }
+}